@@ -1,37 +1,64 @@
-use crate::CountryHolidays;
-use chrono::{Datelike, Month, NaiveDate};
+use crate::i18n::{month_name, tr, weekday_short};
+use crate::leave::Leave;
+use crate::style;
+use crate::{CountryHolidays, Holiday};
+use chrono::{Datelike, NaiveDate, Weekday};
 use colored::{Color, ColoredString, Colorize};
-use num_traits::FromPrimitive;
 use prettytable::{row, Table};
 use std::collections::HashMap;
 
-pub fn print_calendar_comparison(month: u32, year: i32, country_holidays: &[CountryHolidays]) {
+pub fn print_calendar_comparison(
+    month: u32,
+    year: i32,
+    country_holidays: &[CountryHolidays],
+    leave: &[Leave],
+) {
     let first_day = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
     let num_days_in_month = days_in_month(year, month);
     let start_day_of_week = first_day.weekday().num_days_from_sunday();
 
-    println!(
-        "{} {}\nSu Mo Tu We Th Fr Sa",
-        Month::from_u32(month).unwrap().name(),
-        year
-    );
+    let weekday_header = [
+        Weekday::Sun,
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+    ]
+    .map(weekday_short)
+    .join(" ");
+    println!("{} {}\n{}", month_name(month), year, weekday_header);
 
     // Assign a color for each country dynamically
     let colors = assign_colors(country_holidays);
 
     // Create a HashMap to store holidays for quick lookup
-    let mut holiday_map: HashMap<u32, Vec<(&str, &str)>> = HashMap::new();
+    let mut holiday_map: HashMap<u32, Vec<(&str, &Holiday)>> = HashMap::new();
     for country in country_holidays {
         for holiday in &country.holidays {
             if holiday.date.month() == month && holiday.date.year() == year {
                 holiday_map
                     .entry(holiday.date.day())
-                    .or_insert_with(Vec::new)
-                    .push((&country.country, &holiday.name));
+                    .or_default()
+                    .push((&country.country, holiday));
+            }
+            let observed = holiday.observed_date();
+            if observed != holiday.date && observed.month() == month && observed.year() == year {
+                holiday_map
+                    .entry(observed.day())
+                    .or_default()
+                    .push((&country.country, holiday));
             }
         }
     }
 
+    let leave_days: HashMap<u32, &Leave> = leave
+        .iter()
+        .filter(|l| l.date.month() == month && l.date.year() == year)
+        .map(|l| (l.date.day(), l))
+        .collect();
+
     // Calendar view
     for _ in 0..start_day_of_week {
         print!("   ");
@@ -39,15 +66,23 @@ pub fn print_calendar_comparison(month: u32, year: i32, country_holidays: &[Coun
 
     for day in 1..=num_days_in_month {
         if (day + start_day_of_week - 1) % 7 == 0 {
-            print!("\n");
+            println!();
         }
 
-        if let Some(holidays) = holiday_map.get(&day) {
+        if leave_days.contains_key(&day) {
+            print!("{}{}", format!("{:2}", day).white().on_red(), mark('+'));
+        } else if let Some(holidays) = holiday_map.get(&day) {
             let colored_day = match holidays.len() {
                 1 => colorize_day(day, holidays[0].0, &colors),
-                _ => day.to_string().magenta(), // Multiple holidays on the same day
+                _ => format!("{:2}", day).magenta(), // Multiple holidays on the same day
             };
-            print!("{:2} ", colored_day);
+            // Holidays that are not guaranteed days off are dimmed and underlined
+            let colored_day = if holidays.iter().any(|(_, h)| h.kind().is_day_off()) {
+                colored_day
+            } else {
+                colored_day.dimmed().underline()
+            };
+            print!("{}{}", colored_day, mark('*'));
         } else {
             print!("{:2} ", day);
         }
@@ -56,7 +91,12 @@ pub fn print_calendar_comparison(month: u32, year: i32, country_holidays: &[Coun
 
     // Print holidays for each country in a table format
     let mut table = Table::new();
-    table.add_row(row!["Country", "Date", "Holiday"]);
+    table.add_row(row![
+        tr!("header.country"),
+        tr!("header.date"),
+        tr!("header.holiday"),
+        tr!("header.kind")
+    ]);
 
     for country in country_holidays {
         let holidays_this_month: Vec<_> = country
@@ -66,21 +106,65 @@ pub fn print_calendar_comparison(month: u32, year: i32, country_holidays: &[Coun
             .collect();
 
         for holiday in holidays_this_month {
-            let colored_text = colorize_holiday(&holiday.name, &country.country, &colors);
+            let colored_text = colorize_holiday(&holiday.display_name(), &country.country, &colors);
+            let colored_text = if holiday.kind().is_day_off() {
+                colored_text
+            } else {
+                colored_text.dimmed()
+            };
 
             table.add_row(row![
                 &country.country,
                 holiday.date.day().to_string(),
-                colored_text
+                colored_text,
+                holiday.kind().label()
+            ]);
+        }
+
+        let observed_this_month = country.holidays.iter().filter(|holiday| {
+            let observed = holiday.observed_date();
+            observed != holiday.date && observed.month() == month && observed.year() == year
+        });
+
+        for holiday in observed_this_month {
+            let name = tr!("holiday.observed", holiday.display_name());
+            let colored_text = colorize_holiday(&name, &country.country, &colors);
+
+            table.add_row(row![
+                &country.country,
+                holiday.observed_date().day().to_string(),
+                colored_text,
+                holiday.kind().label()
             ]);
         }
     }
 
+    let mut leave_this_month: Vec<_> = leave_days.values().collect();
+    leave_this_month.sort_by_key(|l| l.date);
+    for day in leave_this_month {
+        table.add_row(row![
+            tr!("leave.label"),
+            day.date.day().to_string(),
+            day.label().red(),
+            tr!("kind.leave")
+        ]);
+    }
+
     table.printstd(); // Print the table to standard output
 }
 
+/// Without colors, days with leave or holidays are told apart by a mark after
+/// them.
+fn mark(symbol: char) -> char {
+    if style::color() {
+        ' '
+    } else {
+        symbol
+    }
+}
+
 fn assign_colors(country_holidays: &[CountryHolidays]) -> HashMap<String, Color> {
-    let color_choices = vec![
+    let color_choices = [
         Color::Green,
         Color::Blue,
         Color::Yellow,
@@ -102,8 +186,8 @@ fn assign_colors(country_holidays: &[CountryHolidays]) -> HashMap<String, Color>
 fn colorize_day(day: u32, country: &str, colors: &HashMap<String, Color>) -> ColoredString {
     colors
         .get(country)
-        .map(|&color| day.to_string().color(color))
-        .unwrap_or_else(|| day.to_string().normal())
+        .map(|&color| format!("{:2}", day).color(color))
+        .unwrap_or_else(|| format!("{:2}", day).normal())
 }
 
 fn colorize_holiday(
@@ -0,0 +1,168 @@
+use crate::i18n::{tr, weekday_name, weekday_short};
+use crate::puente::{find_puente_days, is_weekday};
+use crate::style;
+use crate::table::new_table;
+use crate::Holiday;
+use chrono::{Datelike, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, Color};
+
+#[derive(Debug)]
+pub struct ShiftedHoliday {
+    pub name: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+/// How the holidays of a country changed between two years.
+#[derive(Debug)]
+pub struct YearChanges {
+    pub from: i32,
+    pub to: i32,
+    pub added: Vec<Holiday>,
+    pub removed: Vec<Holiday>,
+    pub shifted: Vec<ShiftedHoliday>,
+    pub weekday_holidays: (usize, usize),
+    pub bridges: (usize, usize),
+}
+
+/// Holidays that give a day off during the working week.
+pub fn weekday_holiday_count(holidays: &[Holiday]) -> usize {
+    holidays
+        .iter()
+        .filter(|h| h.kind().is_day_off() && is_weekday(h.observed_date()))
+        .count()
+}
+
+pub fn bridge_count(holidays: &[Holiday]) -> usize {
+    let holiday_refs: Vec<&Holiday> = holidays.iter().collect();
+    find_puente_days(&holiday_refs).len()
+}
+
+pub fn compare_years(
+    from: i32,
+    from_holidays: &[Holiday],
+    to: i32,
+    to_holidays: &[Holiday],
+) -> YearChanges {
+    let added = to_holidays
+        .iter()
+        .filter(|h| !from_holidays.iter().any(|f| f.same_name(h)))
+        .cloned()
+        .collect();
+    let removed = from_holidays
+        .iter()
+        .filter(|h| !to_holidays.iter().any(|t| t.same_name(h)))
+        .cloned()
+        .collect();
+    let shifted = from_holidays
+        .iter()
+        .filter_map(|f| {
+            let t = to_holidays.iter().find(|t| f.same_name(t))?;
+            (f.date.month() != t.date.month() || f.date.day() != t.date.day()).then(|| {
+                ShiftedHoliday {
+                    name: t.name.clone(),
+                    from: f.date,
+                    to: t.date,
+                }
+            })
+        })
+        .collect();
+
+    YearChanges {
+        from,
+        to,
+        added,
+        removed,
+        shifted,
+        weekday_holidays: (
+            weekday_holiday_count(from_holidays),
+            weekday_holiday_count(to_holidays),
+        ),
+        bridges: (bridge_count(from_holidays), bridge_count(to_holidays)),
+    }
+}
+
+pub fn print_changes(changes: &YearChanges, country_code: &str) {
+    println!(
+        "\n{}",
+        tr!("changes.title", country_code, changes.from, changes.to)
+            .bold()
+            .yellow()
+    );
+
+    for (title, holidays, color) in [
+        (tr!("changes.added"), &changes.added, Color::Green),
+        (tr!("changes.removed"), &changes.removed, Color::Red),
+    ] {
+        println!("\n{}", title.bold());
+        if holidays.is_empty() {
+            println!("{}", tr!("none").dimmed());
+            continue;
+        }
+        let mut table = new_table(vec![
+            tr!("header.date"),
+            tr!("header.day"),
+            tr!("header.holiday"),
+        ]);
+        for holiday in holidays {
+            table.add_row(vec![
+                Cell::new(holiday.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+                Cell::new(weekday_name(holiday.date.weekday())).fg(Color::Cyan),
+                Cell::new(&holiday.name).fg(color),
+            ]);
+        }
+        println!("{table}");
+    }
+
+    println!("\n{}", tr!("changes.shifted").bold());
+    if changes.shifted.is_empty() {
+        println!("{}", tr!("none").dimmed());
+    } else {
+        let mut table = new_table(vec![
+            tr!("header.holiday"),
+            changes.from.to_string(),
+            changes.to.to_string(),
+        ]);
+        for shifted in &changes.shifted {
+            table.add_row(vec![
+                Cell::new(&shifted.name).fg(Color::Magenta),
+                Cell::new(format!(
+                    "{} ({})",
+                    shifted.from.format("%d-%m-%Y"),
+                    weekday_short(shifted.from.weekday())
+                ))
+                .fg(Color::Cyan),
+                Cell::new(format!(
+                    "{} ({})",
+                    shifted.to.format("%d-%m-%Y"),
+                    weekday_short(shifted.to.weekday())
+                ))
+                .fg(Color::Cyan),
+            ]);
+        }
+        println!("{table}");
+    }
+
+    println!();
+    for (label, (from, to)) in [
+        (tr!("changes.weekday_holidays"), changes.weekday_holidays),
+        (tr!("changes.bridges"), changes.bridges),
+    ] {
+        let net = to as i64 - from as i64;
+        let net = match net {
+            n if n > 0 => format!("+{}", n).green(),
+            n if n < 0 => n.to_string().red(),
+            n => n.to_string().normal(),
+        };
+        println!(
+            "{} {} {} {} ({})",
+            format!("{}:", label).bold().blue(),
+            from,
+            if style::ascii() { "->" } else { "→" },
+            to,
+            net
+        );
+    }
+    println!();
+}
@@ -1,14 +1,54 @@
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
 
 pub fn cli() -> Command {
     Command::new("conze")
         .version("0.1.0")
         .author("Jain Ramchurn")
+        .arg(
+            Arg::new("kinds")
+                .long("kinds")
+                .short('k')
+                .global(true)
+                .value_delimiter(',')
+                .value_name("KINDS")
+                .help("Only show these kinds of holidays (e.g., public,bank).\nAvailable kinds: public, bank, optional, observance, school"),
+        )
+        .arg(
+            Arg::new("profile")
+                .long("profile")
+                .global(true)
+                .value_name("NAME")
+                .help("Use the settings of this profile from the configuration file"),
+        )
+        .arg(
+            Arg::new("config-file")
+                .long("config")
+                .global(true)
+                .value_name("PATH")
+                .value_parser(clap::value_parser!(std::path::PathBuf))
+                .help("Use this configuration file instead of the default one (or $CONZE_CONFIG)"),
+        )
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .global(true)
+                .value_name("WHEN")
+                .value_parser(["auto", "always", "never"])
+                .default_value("auto")
+                .help("When to color the output. `auto` colors it only in a terminal and honors NO_COLOR and CLICOLOR_FORCE"),
+        )
+        .arg(
+            Arg::new("ascii")
+                .long("ascii")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Print plain ASCII: no emojis and no box-drawing characters in tables"),
+        )
         .subcommand(
             Command::new("bridge")
                 .aliases(["pond", "pont", "pon", "puente"])
-                .arg(Arg::new("month").short('m').long("month").aliases(["mois"]))
-                .arg(Arg::new("year").short('y').long("year").aliases(["lanner"]))
+                .arg(month_arg())
+                .arg(year_arg())
                 .arg(
                     Arg::new("country")
                         .long("country")
@@ -19,8 +59,8 @@ pub fn cli() -> Command {
         .subcommand(
             Command::new("calendar")
                 .aliases(["cal"])
-                .arg(Arg::new("month").short('m').long("month").aliases(["mois"]))
-                .arg(Arg::new("year").short('y').long("year").aliases(["lanner"]))
+                .arg(month_arg())
+                .arg(year_arg())
                 .arg(
                     Arg::new("compare")
                         .long("compare")
@@ -36,9 +76,247 @@ pub fn cli() -> Command {
                         .long("default-country")
                         .value_name("COUNTRY_CODE")
                         .ignore_case(true)
-                        .help("Sets the default country. \nAvailable countries:\n - France (FR)\n - Mauritius (MU)\n - South Africa (ZA)"),
+                        .help("Sets the default country (e.g., MU). Run `conze countries` to see the available ones"),
+                )
+                .arg(
+                    Arg::new("language")
+                        .long("language")
+                        .value_name("LANGUAGE")
+                        .help("Sets the language of the messages: en (English), fr (French) or mfe (Kreol)"),
+                )
+                .subcommand(Command::new("show").about("Displays the current configuration"))
+                .subcommand(
+                    Command::new("get")
+                        .about("Prints the value of a setting")
+                        .arg(config_key_arg()),
+                )
+                .subcommand(
+                    Command::new("set")
+                        .about("Changes a setting")
+                        .arg(config_key_arg())
+                        .arg(Arg::new("value").required(true).help("The new value")),
+                )
+                .subcommand(
+                    Command::new("unset")
+                        .about("Puts a setting back to its default")
+                        .arg(config_key_arg()),
+                )
+                .subcommand(Command::new("list").about("Prints every setting that is set"))
+                .subcommand(
+                    Command::new("edit")
+                        .about("Opens the configuration file in $VISUAL or $EDITOR"),
+                )
+                .subcommand(Command::new("path").about("Prints where the configuration file is"))
+                .subcommand(
+                    Command::new("use")
+                        .about("Makes a profile the default one")
+                        .arg(Arg::new("name").required(true).help("The name of the profile")),
+                )
+                .subcommand(
+                    Command::new("reset").about("Deletes the configuration file, going back to the defaults"),
+                ),
+        )
+        .subcommand(
+            Command::new("leave")
+                .about("Manage your booked leave")
+                .subcommand(
+                    Command::new("add")
+                        .about("Books a day of leave")
+                        .arg(Arg::new("date").required(true).help("Date of the leave (e.g. 2026-05-01, tomorrow, +3d)"))
+                        .arg(
+                            Arg::new("note")
+                                .long("note")
+                                .short('n')
+                                .help("A note about the leave"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("remove")
+                        .aliases(["rm"])
+                        .about("Removes a booked day of leave")
+                        .arg(Arg::new("date").required(true).help("Date of the leave (e.g. 2026-05-01, tomorrow, +3d)")),
+                )
+                .subcommand(
+                    Command::new("list")
+                        .aliases(["ls"])
+                        .about("Lists booked leave")
+                        .arg(
+                            Arg::new("year")
+                                .short('y')
+                                .long("year")
+                                .allow_hyphen_values(true)
+                                .help("Only show leave for this year"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("team")
+                .about("See who in your team is off, based on team.toml")
+                .subcommand(
+                    Command::new("calendar")
+                        .aliases(["cal"])
+                        .about("Shows the month calendar with the team members off each day")
+                        .arg(month_arg())
+                        .arg(year_arg()),
+                )
+                .subcommand(
+                    Command::new("next")
+                        .about("Lists the next days on which team members are off")
+                        .arg(
+                            Arg::new("count")
+                                .short('n')
+                                .long("count")
+                                .value_parser(clap::value_parser!(usize))
+                                .default_value("5")
+                                .help("Number of days to show"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("free-days")
+                .aliases(["free"])
+                .about("Find working days shared by several countries")
+                .arg(
+                    Arg::new("countries")
+                        .long("countries")
+                        .short('c')
+                        .value_delimiter(',')
+                        .help("Countries to check (e.g., MU,FR,ZA). Defaults to the default country"),
+                )
+                .arg(month_arg())
+                .arg(year_arg())
+                .arg(
+                    Arg::new("fewest")
+                        .long("fewest")
+                        .action(ArgAction::SetTrue)
+                        .help("Also show the days on which the fewest countries are off"),
+                ),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Compare the holidays of two countries over a year")
+                .arg(Arg::new("first").required(true).help("First country (e.g., MU)"))
+                .arg(Arg::new("second").required(true).help("Second country (e.g., FR)"))
+                .arg(year_arg())
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .action(ArgAction::SetTrue)
+                        .help("Print the report as JSON"),
+                ),
+        )
+        .subcommand(
+            Command::new("changes")
+                .about("Show how the holidays of a country changed between two years")
+                .arg(
+                    Arg::new("country")
+                        .long("country")
+                        .short('c')
+                        .help("Specify country (e.g., MU for Mauritius, ZA for South Africa)"),
+                )
+                .arg(
+                    Arg::new("from")
+                        .long("from")
+                        .allow_hyphen_values(true)
+                        .help("Year to compare from (default: the year before --to)"),
+                )
+                .arg(
+                    Arg::new("to")
+                        .long("to")
+                        .allow_hyphen_values(true)
+                        .help("Year to compare to (default: the current year)"),
+                ),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Show holiday statistics over several years")
+                .arg(
+                    Arg::new("country")
+                        .long("country")
+                        .short('c')
+                        .help("Specify country (e.g., MU for Mauritius, ZA for South Africa)"),
+                )
+                .arg(
+                    Arg::new("years")
+                        .long("years")
+                        .short('y')
+                        .allow_hyphen_values(true)
+                        .help("A year or a range of years (e.g., 2020..2030). Defaults to every year of the dataset"),
+                ),
+        )
+        .subcommand(
+            Command::new("long-weekends")
+                .aliases(["lw", "weekends"])
+                .about("List the breaks of three or more days off that need no leave")
+                .arg(
+                    Arg::new("country")
+                        .long("country")
+                        .short('c')
+                        .help("Specify country (e.g., MU for Mauritius, ZA for South Africa)"),
                 )
-                .subcommand(Command::new("show").about("Displays the current configuration")),
+                .arg(
+                    Arg::new("year")
+                        .short('y')
+                        .long("year")
+                        .aliases(["lanner"])
+                        .allow_hyphen_values(true)
+                        .help("A year or a range of years (e.g., 2026..2027)"),
+                ),
+        )
+        .subcommand(
+            Command::new("search")
+                .aliases(["find"])
+                .about("Search holidays by name across countries and years")
+                .arg(
+                    Arg::new("pattern")
+                        .required(true)
+                        .help("Text or regular expression to match, ignoring case"),
+                )
+                .arg(
+                    Arg::new("country")
+                        .long("country")
+                        .short('c')
+                        .value_delimiter(',')
+                        .help("Only search these countries (e.g., MU,FR). Defaults to all countries"),
+                )
+                .arg(
+                    Arg::new("year")
+                        .short('y')
+                        .long("year")
+                        .aliases(["lanner"])
+                        .allow_hyphen_values(true)
+                        .conflicts_with("all-years")
+                        .help("A year or a range of years (e.g., 2026..2027). Defaults to the current year"),
+                )
+                .arg(
+                    Arg::new("all-years")
+                        .long("all-years")
+                        .action(ArgAction::SetTrue)
+                        .help("Search every year of the datasets"),
+                ),
+        )
+        .subcommand(
+            Command::new("countries")
+                .about("List the supported countries and the years their datasets cover"),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Check holiday datasets for malformed or suspicious entries")
+                .arg(
+                    Arg::new("country")
+                        .long("country")
+                        .short('c')
+                        .value_delimiter(',')
+                        .conflicts_with("file")
+                        .help("Only check these countries (e.g., MU,FR). Defaults to all countries"),
+                )
+                .arg(
+                    Arg::new("file")
+                        .long("file")
+                        .short('f')
+                        .value_parser(clap::value_parser!(std::path::PathBuf))
+                        .help("Check a local JSON file instead of the published datasets"),
+                ),
         )
         .subcommand(
             Command::new("list")
@@ -53,7 +331,32 @@ pub fn cli() -> Command {
                     Arg::new("year")
                         .short('y')
                         .long("year")
+                        .allow_hyphen_values(true)
                         .help("Specify the year"),
                 ),
         )
 }
+
+fn month_arg() -> Arg {
+    Arg::new("month")
+        .short('m')
+        .long("month")
+        .aliases(["mois"])
+        .allow_hyphen_values(true)
+        .help("Month as a number, a name (may, mai, me, mei), a month of a year (2026-05, may 2026, 2026-W18) or relative (next, last, +3m)")
+}
+
+fn year_arg() -> Arg {
+    Arg::new("year")
+        .short('y')
+        .long("year")
+        .aliases(["lanner"])
+        .allow_hyphen_values(true)
+        .help("Year, e.g. 2026, next year or -1y")
+}
+
+fn config_key_arg() -> Arg {
+    Arg::new("key")
+        .required(true)
+        .help(format!("The setting: {}", crate::config::KEYS.join(", ")))
+}
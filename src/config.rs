@@ -1,33 +1,591 @@
+use crate::countries::is_remote;
+use crate::error::ConzeError;
+use crate::i18n::{tr, Language};
+use crate::leave::Leave;
 use anyhow::{Context, Result};
+use chrono::{NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Serialize, Deserialize)]
+pub const DEFAULT_COUNTRY: &str = "MU";
+
+/// The project settings file, looked for in the current directory and the
+/// ones above it.
+pub const PROJECT_FILE: &str = ".conze.toml";
+
+/// The keys of `config get`, `set` and `unset`. The ones of the `[network]`
+/// table are prefixed with `network.`.
+pub const KEYS: [&str; 10] = [
+    "default_country",
+    "profile",
+    "language",
+    "manifest",
+    "data_url",
+    "network.offline",
+    "network.timeout",
+    "network.retries",
+    "network.proxy",
+    "network.ca_bundle",
+];
+
+/// Environment variables overriding a setting for one invocation, e.g. in
+/// CI where the config file cannot easily be written.
+pub const ENV_OVERRIDES: [(&str, &str); 3] = [
+    ("CONZE_COUNTRY", "default_country"),
+    ("CONZE_DATA_URL", "data_url"),
+    ("CONZE_OFFLINE", "network.offline"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(
+        default = "default_country",
         deserialize_with = "deserialize_uppercase",
         serialize_with = "serialize_uppercase"
     )]
     pub default_country: String,
+    /// Language of the messages, e.g. `fr`. Follows the locale when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Country manifest, a URL or a file, replacing the built-in list of
+    /// countries and where their datasets are.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+    /// Where the datasets are, a URL or a directory, replacing the one of
+    /// every dataset of the manifest. Useful with a mirror.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_url: Option<String>,
+    /// The profile used without `--profile`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    #[serde(default, skip_serializing_if = "NetworkConfig::is_default")]
+    pub network: NetworkConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Settings switched together, under `[profiles.<name>]`, e.g. for a home
+/// and a client country. Paths are relative to the config file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Replaces `default_country`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Shown next to the country, like the region of a team member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// The days off of every week, Saturday and Sunday when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weekend: Option<Vec<Weekday>>,
+    /// Files of extra days off, each a `[[holidays]]` list of `date` and
+    /// `name`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<PathBuf>,
+    /// Leave file used instead of `leave.toml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leave: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct HolidayFile {
+    #[serde(default)]
+    holidays: Vec<Closure>,
+}
+
+impl Profile {
+    /// The extra days off of the `holidays` files.
+    pub fn load_holidays(&self) -> Result<Vec<Leave>, ConzeError> {
+        let mut days = Vec::new();
+        for path in &self.holidays {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| ConzeError::ConfigError(tr!("error.read_file", path.display(), e)))?;
+            let file: HolidayFile = toml::from_str(&text).map_err(|e| {
+                ConzeError::ConfigError(tr!(
+                    "error.invalid_holiday_file",
+                    path.display(),
+                    e.to_string().trim_end()
+                ))
+            })?;
+            days.extend(
+                file.holidays
+                    .into_iter()
+                    .map(|h| Leave::closure(h.date, h.name)),
+            );
+        }
+        Ok(days)
+    }
+
+    /// The country and region, e.g. `FR, Paris`.
+    pub fn location(&self, default_country: &str) -> String {
+        let country = self.country.as_deref().unwrap_or(default_country);
+        match &self.region {
+            Some(region) => format!("{}, {}", country, region),
+            None => country.to_string(),
+        }
+    }
+}
+
+/// How datasets are downloaded, under `[network]`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Never download: only local datasets and the built-in rules are used.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub offline: bool,
+    /// Seconds to wait for a download before giving up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// How many times a failed download is tried again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+    /// Proxy URL, taking precedence over `HTTPS_PROXY`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// PEM file of extra certificate authorities to trust.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
+}
+
+impl NetworkConfig {
+    fn is_default(&self) -> bool {
+        *self == NetworkConfig::default()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            default_country: default_country(),
+            language: None,
+            manifest: None,
+            data_url: None,
+            profile: None,
+            network: NetworkConfig::default(),
+            profiles: BTreeMap::new(),
+        }
+    }
 }
 
 impl Config {
+    /// Reads the config file, or the defaults when there is none.
     pub fn load(config_path: &Path) -> Result<Self> {
+        if !config_path.exists() {
+            return Ok(Config::default());
+        }
         let config = std::fs::read_to_string(config_path)
-            .with_context(|| format!("Failed to read config file at {:?}", config_path))?;
-        let config: Config = toml::from_str(&config).context("Failed to parse config file")?;
+            .with_context(|| tr!("config.read_failed", format!("{:?}", config_path)))?;
+        let mut config: Config =
+            toml::from_str(&config).with_context(|| tr!("config.parse_failed"))?;
+        if let Some(config_dir) = config_path.parent() {
+            config.resolve_paths(config_dir);
+        }
+        config.validate()?;
         Ok(config)
     }
 
+    /// The language set in the config file, read leniently so that errors
+    /// in the rest of the file can be reported in it.
+    pub fn language_in(config_path: &Path) -> Option<Language> {
+        let text = std::fs::read_to_string(config_path).ok()?;
+        let table: toml::Table = toml::from_str(&text).ok()?;
+        Language::from_code(table.get("language")?.as_str()?)
+    }
+
+    /// Makes the paths written by hand relative to the config file absolute,
+    /// so they do not depend on where conze is run.
+    fn resolve_paths(&mut self, config_dir: &Path) {
+        let paths = [self.manifest.as_mut(), self.data_url.as_mut()];
+        for path in paths.into_iter().flatten() {
+            if !is_remote(path) && Path::new(path.as_str()).is_relative() {
+                *path = config_dir.join(&*path).to_string_lossy().into_owned();
+            }
+        }
+        if let Some(path) = self.network.ca_bundle.as_mut() {
+            if path.is_relative() {
+                *path = config_dir.join(&*path);
+            }
+        }
+    }
+
     pub fn save(&self, config_path: &Path) -> Result<()> {
+        self.validate()?;
         let toml_string = toml::to_string(self)?;
         std::fs::create_dir_all(config_path.parent().unwrap())?;
         std::fs::write(config_path, toml_string)?;
         Ok(())
     }
+
+    /// Checks every value as `config set` would, except that files and
+    /// directories are only required to exist once they are used, so that a
+    /// config pointing to a deleted one can still be repaired.
+    fn validate(&self) -> Result<(), ConzeError> {
+        let mut checked = Config {
+            profiles: self.profiles.clone(),
+            ..Config::default()
+        };
+        for key in KEYS {
+            if let Some(value) = self.get(key)? {
+                checked.assign(key, &value, false)?;
+            }
+        }
+        for (name, profile) in &self.profiles {
+            if let Some(country) = &profile.country {
+                checked
+                    .set("default_country", country)
+                    .map_err(|e| invalid_profile(name, e.to_string()))?;
+            }
+            if profile.weekend.as_ref().is_some_and(|days| days.len() >= 7) {
+                return Err(invalid_profile(name, tr!("config.expected_weekend")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Switches to the profile `name`, or to the default profile when
+    /// `None`, returning it with its paths resolved against `config_dir`.
+    pub fn apply_profile(
+        &mut self,
+        name: Option<&str>,
+        config_dir: &Path,
+    ) -> Result<Option<Profile>, ConzeError> {
+        let Some(name) = name.or(self.profile.as_deref()).map(str::to_string) else {
+            return Ok(None);
+        };
+        let mut profile = self
+            .profiles
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| self.unknown_profile(&name))?;
+        self.profile = Some(name);
+        if let Some(country) = &profile.country {
+            self.default_country = country.to_uppercase();
+        }
+        for path in profile.holidays.iter_mut().chain(profile.leave.as_mut()) {
+            *path = config_dir.join(&*path);
+        }
+        Ok(Some(profile))
+    }
+
+    fn unknown_profile(&self, name: &str) -> ConzeError {
+        let names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+        ConzeError::InvalidInput(tr!("error.unknown_profile", name, names.join(", ")))
+    }
+
+    /// The value of `key` as written in the file, `None` when unset.
+    pub fn get(&self, key: &str) -> Result<Option<String>, ConzeError> {
+        let network = &self.network;
+        Ok(match key {
+            "default_country" => Some(self.default_country.clone()),
+            "profile" => self.profile.clone(),
+            "language" => self.language.clone(),
+            "manifest" => self.manifest.clone(),
+            "data_url" => self.data_url.clone(),
+            "network.offline" => network.offline.then(|| "true".to_string()),
+            "network.timeout" => network.timeout.map(|t| t.to_string()),
+            "network.retries" => network.retries.map(|r| r.to_string()),
+            "network.proxy" => network.proxy.clone(),
+            "network.ca_bundle" => network.ca_bundle.as_ref().map(|p| p.display().to_string()),
+            _ => return Err(unknown_key(key)),
+        })
+    }
+
+    /// Sets `key` after checking `value`. Whether a default country is
+    /// supported is left to the caller, which knows the countries.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConzeError> {
+        self.assign(key, value, true)
+    }
+
+    /// Sets `key`, checking that the files and directories it names exist
+    /// when `check_paths`.
+    fn assign(&mut self, key: &str, value: &str, check_paths: bool) -> Result<(), ConzeError> {
+        let value = value.trim();
+        let invalid = |expected: String| {
+            ConzeError::InvalidInput(tr!("error.config_invalid_value", value, key, expected))
+        };
+        let network = &mut self.network;
+        match key {
+            "default_country" => {
+                if value.len() != 2 || !value.chars().all(|c| c.is_ascii_alphabetic()) {
+                    return Err(invalid(tr!("config.expected_country")));
+                }
+                self.default_country = value.to_uppercase();
+            }
+            "profile" => {
+                if !self.profiles.contains_key(value) {
+                    return Err(self.unknown_profile(value));
+                }
+                self.profile = Some(value.to_string());
+            }
+            "language" => {
+                let language = Language::from_code(value)
+                    .ok_or_else(|| invalid(tr!("config.expected_language")))?;
+                self.language = Some(language.code().to_string());
+            }
+            "manifest" => {
+                if check_paths && !is_remote(value) && !Path::new(value).is_file() {
+                    return Err(invalid(tr!("config.expected_manifest")));
+                }
+                self.manifest = Some(absolute(value));
+            }
+            "data_url" => {
+                if check_paths && !is_remote(value) && !Path::new(value).is_dir() {
+                    return Err(invalid(tr!("config.expected_data_url")));
+                }
+                self.data_url = Some(absolute(value));
+            }
+            "network.offline" => {
+                network.offline = match value.to_lowercase().as_str() {
+                    "true" | "yes" | "on" | "1" => true,
+                    "false" | "no" | "off" | "0" => false,
+                    _ => return Err(invalid(tr!("config.expected_bool"))),
+                };
+            }
+            "network.timeout" => {
+                let timeout = value
+                    .parse()
+                    .ok()
+                    .filter(|&t: &u64| t > 0)
+                    .ok_or_else(|| invalid(tr!("config.expected_seconds")))?;
+                network.timeout = Some(timeout);
+            }
+            "network.retries" => {
+                let retries = value
+                    .parse()
+                    .map_err(|_| invalid(tr!("config.expected_count")))?;
+                network.retries = Some(retries);
+            }
+            "network.proxy" => {
+                reqwest::Url::parse(value).map_err(|_| invalid(tr!("config.expected_url")))?;
+                network.proxy = Some(value.to_string());
+            }
+            "network.ca_bundle" => {
+                if check_paths && !Path::new(value).is_file() {
+                    return Err(invalid(tr!("config.expected_file")));
+                }
+                network.ca_bundle = Some(PathBuf::from(absolute(value)));
+            }
+            _ => return Err(unknown_key(key)),
+        }
+        Ok(())
+    }
+
+    /// Applies the settings of a project file over these ones.
+    pub fn apply_project(&mut self, project: &ProjectConfig) -> Result<(), ConzeError> {
+        for (key, value) in &project.settings {
+            self.set(key, value)
+                .map_err(|error| project.invalid(error.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Applies the [`ENV_OVERRIDES`] that are set.
+    pub fn apply_env(&mut self) -> Result<(), ConzeError> {
+        for (var, key) in ENV_OVERRIDES {
+            let Some(value) = std::env::var(var).ok().filter(|value| !value.is_empty()) else {
+                continue;
+            };
+            self.set(key, &value)
+                .map_err(|error| ConzeError::ConfigError(tr!("error.invalid_env", var, error)))?;
+        }
+        Ok(())
+    }
+
+    /// Goes back to the default value of `key`.
+    pub fn unset(&mut self, key: &str) -> Result<(), ConzeError> {
+        let network = &mut self.network;
+        match key {
+            "default_country" => self.default_country = DEFAULT_COUNTRY.to_string(),
+            "profile" => self.profile = None,
+            "language" => self.language = None,
+            "manifest" => self.manifest = None,
+            "data_url" => self.data_url = None,
+            "network.offline" => network.offline = false,
+            "network.timeout" => network.timeout = None,
+            "network.retries" => network.retries = None,
+            "network.proxy" => network.proxy = None,
+            "network.ca_bundle" => network.ca_bundle = None,
+            _ => return Err(unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+/// Opens the config file in `$VISUAL` or `$EDITOR`, creating it first if
+/// needed, then checks what was saved.
+pub fn edit(config_path: &Path) -> Result<(), ConzeError> {
+    if !config_path.exists() {
+        Config::default().save(config_path)?;
+    }
+
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or(DEFAULT_EDITOR);
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(config_path)
+        .status()
+        .map_err(|e| ConzeError::ConfigError(tr!("error.editor_failed", editor, e)))?;
+    if !status.success() {
+        return Err(ConzeError::ConfigError(tr!(
+            "error.editor_failed",
+            editor,
+            status
+        )));
+    }
+
+    Config::load(config_path).map_err(|error| invalid_file(config_path, error))?;
+    Ok(())
+}
+
+const DEFAULT_EDITOR: &str = if cfg!(windows) { "notepad" } else { "vi" };
+
+/// The error for a config file that cannot be used, with how to fix it.
+pub fn invalid_file(config_path: &Path, error: anyhow::Error) -> ConzeError {
+    ConzeError::ConfigError(tr!(
+        "error.invalid_config_file",
+        config_path.display(),
+        format!("{:#}", error).trim_end()
+    ))
+}
+
+/// The settings of a project, e.g. a team's repository, from a
+/// [`PROJECT_FILE`]. It has the same keys as `config.toml`, plus the team's
+/// closure days:
+///
+/// ```toml
+/// default_country = "FR"
+///
+/// [[closures]]
+/// date = "2026-12-24"
+/// name = "Office closed"
+/// ```
+#[derive(Debug)]
+pub struct ProjectConfig {
+    pub path: PathBuf,
+    /// `(key, value)` pairs, as given to [`Config::set`].
+    settings: Vec<(String, String)>,
+    pub closures: Vec<Leave>,
+}
+
+#[derive(Deserialize)]
+struct Closure {
+    date: NaiveDate,
+    name: String,
+}
+
+impl ProjectConfig {
+    /// The value the project gives to `key`, if any.
+    pub fn setting(&self, key: &str) -> Option<&str> {
+        self.settings
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The nearest project file in `dir` or above it.
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(PROJECT_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Reads a project file. Relative paths in it are relative to its
+    /// directory.
+    pub fn load(path: &Path) -> Result<Self, ConzeError> {
+        let mut project = ProjectConfig {
+            path: path.to_path_buf(),
+            settings: Vec::new(),
+            closures: Vec::new(),
+        };
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| ConzeError::ConfigError(tr!("error.read_file", path.display(), e)))?;
+        let table: toml::Table =
+            toml::from_str(&text).map_err(|e| project.invalid(e.to_string()))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+
+        for (name, value) in table {
+            match (name.as_str(), value) {
+                ("closures", value) => {
+                    let closures: Vec<Closure> = value
+                        .try_into()
+                        .map_err(|e: toml::de::Error| project.invalid(e.to_string()))?;
+                    project.closures = closures
+                        .into_iter()
+                        .map(|c| Leave::closure(c.date, c.name))
+                        .collect();
+                }
+                ("network", toml::Value::Table(network)) => {
+                    for (name, value) in network {
+                        project.add(dir, format!("network.{}", name), value)?;
+                    }
+                }
+                (_, value) => project.add(dir, name, value)?,
+            }
+        }
+        Ok(project)
+    }
+
+    fn add(&mut self, dir: &Path, key: String, value: toml::Value) -> Result<(), ConzeError> {
+        if !KEYS.contains(&key.as_str()) {
+            return Err(self.invalid(unknown_key(&key).to_string()));
+        }
+        let mut value = match value {
+            toml::Value::String(value) => value,
+            value => value.to_string(),
+        };
+        let is_path = matches!(key.as_str(), "manifest" | "data_url" | "network.ca_bundle");
+        if is_path && !is_remote(&value) && Path::new(&value).is_relative() {
+            value = dir.join(&value).to_string_lossy().into_owned();
+        }
+        self.settings.push((key, value));
+        Ok(())
+    }
+
+    fn invalid(&self, reason: String) -> ConzeError {
+        ConzeError::ConfigError(tr!(
+            "error.invalid_project_file",
+            self.path.display(),
+            reason.trim_end()
+        ))
+    }
+}
+
+/// `location`, a URL or a path, with a relative path made absolute from the
+/// current directory.
+fn absolute(location: &str) -> String {
+    let path = Path::new(location);
+    if is_remote(location) || path.is_absolute() {
+        return location.to_string();
+    }
+    match std::env::current_dir() {
+        Ok(dir) => dir
+            .join(path)
+            .components()
+            .collect::<PathBuf>()
+            .to_string_lossy()
+            .into_owned(),
+        Err(_) => location.to_string(),
+    }
+}
+
+fn default_country() -> String {
+    DEFAULT_COUNTRY.to_string()
+}
+
+fn invalid_profile(name: &str, reason: String) -> ConzeError {
+    ConzeError::InvalidInput(tr!("error.invalid_profile", name, reason))
+}
+
+fn unknown_key(key: &str) -> ConzeError {
+    ConzeError::InvalidInput(tr!("error.config_unknown_key", key, KEYS.join(", ")))
 }
 
-fn deserialize_uppercase<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub fn deserialize_uppercase<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
@@ -35,7 +593,7 @@ where
     Ok(s.to_uppercase())
 }
 
-fn serialize_uppercase<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
+pub fn serialize_uppercase<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
@@ -0,0 +1,223 @@
+use crate::error::ConzeError;
+use crate::http::HttpClient;
+use crate::i18n::tr;
+use crate::style;
+use crate::table::new_table;
+use colored::Colorize;
+use comfy_table::{Cell, Color};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// The countries known without a `manifest` in the config.
+const BUILTIN_MANIFEST: &str = include_str!("countries.json");
+
+/// A country with a holiday dataset, as listed in a manifest.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code, e.g. `MU`.
+    pub code: String,
+    pub name: String,
+    /// Derived from the code when not given.
+    #[serde(default)]
+    flag: Option<String>,
+    /// Where the dataset is, a URL or a file. Relative to the manifest.
+    pub url: String,
+    /// The years the dataset covers, when the manifest says.
+    #[serde(default)]
+    pub years: Vec<i32>,
+}
+
+impl Country {
+    pub fn flag(&self) -> String {
+        match &self.flag {
+            Some(flag) => flag.clone(),
+            None => self
+                .code
+                .chars()
+                .filter_map(|c| char::from_u32(0x1F1E6 + (c as u32).checked_sub('A' as u32)?))
+                .collect(),
+        }
+    }
+
+    /// E.g. `Mauritius (MU)`.
+    pub fn label(&self) -> String {
+        format!("{} ({})", self.name, self.code)
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    countries: Vec<Country>,
+}
+
+/// The supported countries, from the built-in manifest or the one set in the
+/// config.
+#[derive(Debug)]
+pub struct CountryRegistry {
+    countries: Vec<Country>,
+}
+
+impl CountryRegistry {
+    /// Loads the countries of `manifest`, a URL or a file, or the built-in
+    /// ones when `None`. A remote manifest cannot be used `offline`.
+    pub async fn load(
+        manifest: Option<&str>,
+        client: &HttpClient,
+        offline: bool,
+    ) -> Result<Self, ConzeError> {
+        let Some(manifest) = manifest else {
+            return Self::parse(BUILTIN_MANIFEST, "", "");
+        };
+
+        if is_remote(manifest) {
+            if offline {
+                return Err(ConzeError::ConfigError(tr!(
+                    "error.offline_manifest",
+                    manifest
+                )));
+            }
+            let text = client.get_text(manifest).await.map_err(|e| {
+                ConzeError::ConfigError(tr!("error.manifest_fetch_failed", manifest, e))
+            })?;
+            let base = &manifest[..manifest.rfind('/').map_or(0, |i| i + 1)];
+            Self::parse(&text, manifest, base)
+        } else {
+            let text = std::fs::read_to_string(manifest)
+                .map_err(|e| ConzeError::ConfigError(tr!("error.read_file", manifest, e)))?;
+            let base = Path::new(manifest)
+                .parent()
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            Self::parse(&text, manifest, &base)
+        }
+    }
+
+    /// Parses a manifest, resolving relative dataset URLs against `base`.
+    fn parse(text: &str, source: &str, base: &str) -> Result<Self, ConzeError> {
+        let invalid =
+            |reason: String| ConzeError::ConfigError(tr!("error.invalid_manifest", source, reason));
+
+        let manifest: Manifest = serde_json::from_str(text).map_err(|e| invalid(e.to_string()))?;
+        if manifest.countries.is_empty() {
+            return Err(invalid(tr!("error.manifest_empty")));
+        }
+
+        let mut seen = HashSet::new();
+        let mut countries = Vec::new();
+        for mut country in manifest.countries {
+            country.code = country.code.trim().to_uppercase();
+            if country.code.len() != 2 || !country.code.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(invalid(tr!("error.manifest_code", country.code)));
+            }
+            if !seen.insert(country.code.clone()) {
+                return Err(invalid(tr!("error.manifest_duplicate", country.code)));
+            }
+            if !is_remote(&country.url)
+                && !Path::new(&country.url).is_absolute()
+                && !base.is_empty()
+            {
+                country.url = if is_remote(base) {
+                    format!("{}{}", base, country.url)
+                } else {
+                    Path::new(base)
+                        .join(&country.url)
+                        .to_string_lossy()
+                        .into_owned()
+                };
+            }
+            country.years.sort();
+            countries.push(country);
+        }
+        countries.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(CountryRegistry { countries })
+    }
+
+    /// Moves every dataset to `data_url`, a URL or a directory, keeping
+    /// their file names.
+    pub fn rebase(&mut self, data_url: &str) {
+        for country in &mut self.countries {
+            let file_name = country.url.rsplit(['/', '\\']).next().unwrap_or_default();
+            country.url = if is_remote(data_url) {
+                format!("{}/{}", data_url.trim_end_matches('/'), file_name)
+            } else {
+                Path::new(data_url)
+                    .join(file_name)
+                    .to_string_lossy()
+                    .into_owned()
+            };
+        }
+    }
+
+    pub fn get(&self, code: &str) -> Option<&Country> {
+        self.countries.iter().find(|country| country.code == code)
+    }
+
+    /// Every country, in the order of their codes.
+    pub fn iter(&self) -> impl Iterator<Item = &Country> {
+        self.countries.iter()
+    }
+
+    pub fn unsupported(&self, code: &str) -> ConzeError {
+        ConzeError::UnsupportedCountry {
+            country: code.to_string(),
+            available: self.countries.iter().map(Country::label).collect(),
+        }
+    }
+}
+
+/// Whether a dataset or manifest location is downloaded rather than read
+/// from disk.
+pub fn is_remote(location: &str) -> bool {
+    location.starts_with("https://") || location.starts_with("http://")
+}
+
+/// Prints every country with the years its dataset covers.
+pub fn print_countries(countries: &[(&Country, Vec<i32>)]) {
+    println!("\n{}", tr!("countries.title").bold().yellow());
+
+    let mut table = new_table(vec![
+        tr!("header.code"),
+        tr!("header.country"),
+        tr!("header.years"),
+    ]);
+    for (country, years) in countries {
+        table.add_row(vec![
+            Cell::new(&country.code).fg(Color::Yellow),
+            Cell::new(if style::ascii() {
+                country.name.clone()
+            } else {
+                format!("{} {}", country.flag(), country.name)
+            })
+            .fg(Color::Green),
+            Cell::new(format_years(years)).fg(Color::Cyan),
+        ]);
+    }
+    println!("{table}");
+    println!(
+        "\n{}",
+        tr!("countries.total", countries.len()).bold().blue()
+    );
+    println!();
+}
+
+/// E.g. `2020..2023, 2025`.
+fn format_years(years: &[i32]) -> String {
+    let mut runs: Vec<(i32, i32)> = Vec::new();
+    for &year in years {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == year => *end = year,
+            _ => runs.push((year, year)),
+        }
+    }
+    runs.iter()
+        .map(|&(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{}..{}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,167 @@
+use crate::countries::{is_remote, CountryRegistry};
+use crate::error::ConzeError;
+use crate::http::HttpClient;
+use crate::i18n::tr;
+use crate::validate::{self, Issue};
+use crate::HolidaysByYear;
+use colored::Colorize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+
+/// The holiday datasets of one invocation. Each country is downloaded at
+/// most once, and several countries are downloaded concurrently.
+///
+/// Every download is checked by [`validate::check`]. Problems are reported on
+/// stderr unless the soft check is turned off, and a dataset that cannot be
+/// decoded at all fails with [`ConzeError::InvalidData`].
+///
+/// Offline, datasets that would be downloaded are left empty, so only the
+/// built-in rules are used for those countries.
+pub struct Datasets {
+    client: HttpClient,
+    countries: CountryRegistry,
+    loaded: HashMap<String, Arc<HolidaysByYear>>,
+    issues: HashMap<String, Vec<Issue>>,
+    soft_check: bool,
+    offline: bool,
+}
+
+impl Datasets {
+    pub fn new(client: HttpClient, countries: CountryRegistry) -> Self {
+        Datasets {
+            client,
+            countries,
+            loaded: HashMap::new(),
+            issues: HashMap::new(),
+            soft_check: true,
+            offline: false,
+        }
+    }
+
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// Whether to warn on stderr about problems in the downloaded data.
+    pub fn set_soft_check(&mut self, enabled: bool) {
+        self.soft_check = enabled;
+    }
+
+    /// The problems found in the dataset of a country downloaded so far.
+    pub fn issues(&self, country_code: &str) -> &[Issue] {
+        self.issues.get(country_code).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn registry(&self) -> &CountryRegistry {
+        &self.countries
+    }
+
+    /// The codes of every supported country, in alphabetical order.
+    pub fn countries(&self) -> Vec<String> {
+        self.countries.iter().map(|c| c.code.clone()).collect()
+    }
+
+    pub async fn get(&mut self, country_code: &str) -> Result<Arc<HolidaysByYear>, ConzeError> {
+        let mut datasets = self.get_all(&[country_code]).await?;
+        Ok(datasets.remove(0))
+    }
+
+    /// The datasets of `country_codes`, in the same order, downloading the
+    /// ones not loaded yet all at once.
+    pub async fn get_all<S: AsRef<str>>(
+        &mut self,
+        country_codes: &[S],
+    ) -> Result<Vec<Arc<HolidaysByYear>>, ConzeError> {
+        let mut downloads = JoinSet::new();
+        let mut pending = HashSet::new();
+        for country_code in country_codes.iter().map(AsRef::as_ref) {
+            let country = self
+                .countries
+                .get(country_code)
+                .ok_or_else(|| self.countries.unsupported(country_code))?;
+            if self.loaded.contains_key(country_code) || !pending.insert(country_code) {
+                continue;
+            }
+            if self.offline && is_remote(&country.url) {
+                let empty = HolidaysByYear {
+                    years: HashMap::new(),
+                };
+                self.loaded
+                    .insert(country_code.to_string(), Arc::new(empty));
+                continue;
+            }
+            let client = self.client.clone();
+            let country_code = country_code.to_string();
+            let url = country.url.clone();
+            downloads.spawn(async move {
+                let text = if is_remote(&url) {
+                    client
+                        .get_text(&url)
+                        .await
+                        .map_err(|source| ConzeError::NetworkError {
+                            country: country_code.clone(),
+                            source,
+                        })
+                } else {
+                    tokio::fs::read_to_string(&url)
+                        .await
+                        .map_err(|e| ConzeError::ConfigError(tr!("error.read_file", url, e)))
+                };
+                (country_code, text)
+            });
+        }
+
+        let mut failure = None;
+        while let Some(download) = downloads.join_next().await {
+            let (country_code, text) = download.expect("dataset download panicked");
+            match text {
+                Ok(text) => {
+                    if let Err(error) = self.decode(&country_code, &text) {
+                        failure.get_or_insert(error);
+                    }
+                }
+                Err(error) => {
+                    failure.get_or_insert(error);
+                }
+            }
+        }
+        if let Some(error) = failure {
+            return Err(error);
+        }
+
+        Ok(country_codes
+            .iter()
+            .map(|c| Arc::clone(&self.loaded[c.as_ref()]))
+            .collect())
+    }
+
+    fn decode(&mut self, country_code: &str, text: &str) -> Result<(), ConzeError> {
+        let mut issues = validate::check(text);
+        let decoded = serde_json::from_str::<HolidaysByYear>(text);
+        if let Err(e) = &decoded {
+            if validate::error_count(&issues) == 0 {
+                issues.push(validate::json_error(e));
+            }
+        }
+
+        let errors = validate::error_count(&issues);
+        if self.soft_check && errors > 0 {
+            let warning = tr!("validate.soft_check", country_code, errors);
+            eprintln!("{}", warning.yellow());
+            if decoded.is_err() {
+                for issue in issues.iter().filter(|issue| issue.is_error()) {
+                    eprintln!("{}", issue);
+                }
+            }
+        }
+        self.issues.insert(country_code.to_string(), issues);
+
+        let data = decoded.map_err(|_| ConzeError::InvalidData {
+            dataset: country_code.to_string(),
+            errors,
+        })?;
+        self.loaded.insert(country_code.to_string(), Arc::new(data));
+        Ok(())
+    }
+}
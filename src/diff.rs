@@ -0,0 +1,157 @@
+use crate::i18n::{tr, weekday_name};
+use crate::table::new_table;
+use crate::Holiday;
+use chrono::{Datelike, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct SharedHoliday {
+    pub date: NaiveDate,
+    pub first: String,
+    pub second: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MovedHoliday {
+    pub name: String,
+    pub first: NaiveDate,
+    pub second: NaiveDate,
+}
+
+/// How the holidays of two countries compare over one year.
+#[derive(Debug, Serialize)]
+pub struct HolidayDiff {
+    pub first: String,
+    pub second: String,
+    pub year: i32,
+    /// Holidays on the same date in both countries.
+    pub shared: Vec<SharedHoliday>,
+    /// Holidays with the same name on different dates.
+    pub moved: Vec<MovedHoliday>,
+    pub only_first: Vec<Holiday>,
+    pub only_second: Vec<Holiday>,
+}
+
+pub fn diff_holidays(
+    first: &str,
+    first_holidays: &[Holiday],
+    second: &str,
+    second_holidays: &[Holiday],
+    year: i32,
+) -> HolidayDiff {
+    let mut shared = Vec::new();
+    let mut moved = Vec::new();
+    let mut only_first = Vec::new();
+
+    for holiday in first_holidays {
+        if let Some(other) = second_holidays.iter().find(|h| h.date == holiday.date) {
+            shared.push(SharedHoliday {
+                date: holiday.date,
+                first: holiday.name.clone(),
+                second: other.name.clone(),
+            });
+        } else if let Some(other) = second_holidays.iter().find(|h| h.same_name(holiday)) {
+            moved.push(MovedHoliday {
+                name: holiday.name.clone(),
+                first: holiday.date,
+                second: other.date,
+            });
+        } else {
+            only_first.push(holiday.clone());
+        }
+    }
+
+    let only_second = second_holidays
+        .iter()
+        .filter(|holiday| {
+            !first_holidays
+                .iter()
+                .any(|h| h.date == holiday.date || h.same_name(holiday))
+        })
+        .cloned()
+        .collect();
+
+    shared.sort_by_key(|s| s.date);
+    moved.sort_by_key(|m| m.first);
+    only_first.sort_by_key(|h| h.date);
+
+    HolidayDiff {
+        first: first.to_string(),
+        second: second.to_string(),
+        year,
+        shared,
+        moved,
+        only_first,
+        only_second,
+    }
+}
+
+pub fn print_diff(diff: &HolidayDiff) {
+    println!(
+        "\n{}",
+        tr!("diff.title", diff.first, diff.second, diff.year)
+            .bold()
+            .yellow()
+    );
+
+    println!("\n{}", tr!("diff.shared").bold().green());
+    let mut table = new_table(vec![
+        tr!("header.date"),
+        diff.first.clone(),
+        diff.second.clone(),
+    ]);
+    for shared in &diff.shared {
+        table.add_row(vec![
+            Cell::new(shared.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+            Cell::new(&shared.first).fg(Color::Green),
+            Cell::new(&shared.second).fg(Color::Green),
+        ]);
+    }
+    print_table_or_none(&table, diff.shared.is_empty());
+
+    println!("\n{}", tr!("diff.moved").bold().magenta());
+    let mut table = new_table(vec![
+        tr!("header.holiday"),
+        diff.first.clone(),
+        diff.second.clone(),
+    ]);
+    for moved in &diff.moved {
+        table.add_row(vec![
+            Cell::new(&moved.name).fg(Color::Magenta),
+            Cell::new(moved.first.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+            Cell::new(moved.second.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+        ]);
+    }
+    print_table_or_none(&table, diff.moved.is_empty());
+
+    for (country, holidays) in [
+        (&diff.first, &diff.only_first),
+        (&diff.second, &diff.only_second),
+    ] {
+        println!("\n{}", tr!("diff.only", country).bold().blue());
+        let mut table = new_table(vec![
+            tr!("header.date"),
+            tr!("header.day"),
+            tr!("header.holiday"),
+        ]);
+        for holiday in holidays {
+            table.add_row(vec![
+                Cell::new(holiday.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+                Cell::new(weekday_name(holiday.date.weekday())).fg(Color::Cyan),
+                Cell::new(holiday.display_name()).fg(Color::Blue),
+            ]);
+        }
+        print_table_or_none(&table, holidays.is_empty());
+    }
+    println!();
+}
+
+fn print_table_or_none(table: &Table, empty: bool) {
+    if empty {
+        println!("{}", tr!("none").dimmed());
+    } else {
+        println!("{table}");
+    }
+}
@@ -0,0 +1,89 @@
+use crate::i18n::tr;
+use std::fmt;
+
+/// Everything that can make a command fail, each with its own exit code so
+/// scripts can tell them apart.
+#[derive(Debug)]
+pub enum ConzeError {
+    /// The holiday dataset of a country could not be downloaded.
+    NetworkError {
+        country: String,
+        source: reqwest::Error,
+    },
+    /// A country missing from the country registry, with the `available`
+    /// ones, e.g. `Mauritius (MU)`.
+    UnsupportedCountry {
+        country: String,
+        available: Vec<String>,
+    },
+    /// Neither the dataset nor the built-in rules cover the year, or any of
+    /// the years asked for when `year` is `None`.
+    NoDataForYear { country: String, year: Option<i32> },
+    /// A command-line argument could not be understood.
+    InvalidInput(String),
+    /// The configuration, leave or team file could not be read or written.
+    ConfigError(String),
+    /// A holiday dataset, downloaded or given with `validate --file`, has
+    /// `errors` problems, which have already been reported.
+    InvalidData { dataset: String, errors: usize },
+}
+
+impl ConzeError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ConzeError::InvalidInput(_) => 2,
+            ConzeError::UnsupportedCountry { .. } => 3,
+            ConzeError::NoDataForYear { .. } => 4,
+            ConzeError::NetworkError { .. } => 5,
+            ConzeError::ConfigError(_) => 6,
+            ConzeError::InvalidData { .. } => 7,
+        }
+    }
+}
+
+impl fmt::Display for ConzeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConzeError::NetworkError { country, source } => {
+                write!(f, "{}: {}", tr!("error.fetch_failed", country), source)
+            }
+            ConzeError::UnsupportedCountry { country, available } => {
+                writeln!(f, "{}", tr!("error.unsupported_country", country))?;
+                write!(f, "{}", tr!("error.available_countries"))?;
+                for label in available {
+                    write!(f, "\n  - {}", label)?;
+                }
+                Ok(())
+            }
+            ConzeError::NoDataForYear {
+                country,
+                year: Some(year),
+            } => write!(f, "{}", tr!("error.no_data_year", country, year)),
+            ConzeError::NoDataForYear {
+                country,
+                year: None,
+            } => write!(f, "{}", tr!("error.no_data", country)),
+            ConzeError::InvalidData { dataset, errors } => {
+                write!(f, "{}", tr!("error.invalid_data", dataset, errors))
+            }
+            ConzeError::InvalidInput(message) | ConzeError::ConfigError(message) => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConzeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConzeError::NetworkError { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for ConzeError {
+    fn from(error: anyhow::Error) -> Self {
+        ConzeError::ConfigError(format!("{:#}", error))
+    }
+}
@@ -0,0 +1,97 @@
+use crate::i18n::{month_name, tr, weekday_name};
+use crate::puente::is_weekday;
+use crate::table::new_table;
+use crate::CountryHolidays;
+use chrono::{Datelike, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, Color};
+
+/// Countries for which `date` is a day off because of a holiday.
+fn countries_off(date: NaiveDate, country_holidays: &[CountryHolidays]) -> Vec<&str> {
+    country_holidays
+        .iter()
+        .filter(|c| {
+            c.holidays
+                .iter()
+                .any(|h| h.observed_date() == date && h.kind().is_day_off())
+        })
+        .map(|c| c.country.as_str())
+        .collect()
+}
+
+pub fn print_free_days(
+    month: u32,
+    year: i32,
+    country_holidays: &[CountryHolidays],
+    show_fewest: bool,
+) {
+    let countries = country_holidays
+        .iter()
+        .map(|c| c.country.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let weekdays: Vec<(NaiveDate, Vec<&str>)> = NaiveDate::from_ymd_opt(year, month, 1)
+        .unwrap()
+        .iter_days()
+        .take_while(|d| d.month() == month)
+        .filter(|&d| is_weekday(d))
+        .map(|d| (d, countries_off(d, country_holidays)))
+        .collect();
+
+    println!(
+        "\n{}",
+        tr!("free_days.title", month_name(month), year, countries)
+            .bold()
+            .yellow()
+    );
+
+    let free_days: Vec<NaiveDate> = weekdays
+        .iter()
+        .filter(|(_, off)| off.is_empty())
+        .map(|(date, _)| *date)
+        .collect();
+
+    if free_days.is_empty() {
+        println!("\n{}", tr!("free_days.none").bold().red());
+    } else {
+        let mut table = new_table(vec![tr!("header.date"), tr!("header.day")]);
+        for date in &free_days {
+            table.add_row(vec![
+                Cell::new(date.format("%d-%m-%Y").to_string()).fg(Color::Green),
+                Cell::new(weekday_name(date.weekday())).fg(Color::Green),
+            ]);
+        }
+        println!("{table}");
+        println!(
+            "\n{}",
+            tr!("free_days.found", free_days.len()).bold().green()
+        );
+    }
+
+    if show_fewest {
+        let fewest = weekdays
+            .iter()
+            .map(|(_, off)| off.len())
+            .filter(|&n| n > 0)
+            .min();
+
+        if let Some(fewest) = fewest {
+            let mut table = new_table(vec![
+                tr!("header.date"),
+                tr!("header.day"),
+                tr!("header.off_in"),
+            ]);
+            for (date, off) in weekdays.iter().filter(|(_, off)| off.len() == fewest) {
+                table.add_row(vec![
+                    Cell::new(date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+                    Cell::new(weekday_name(date.weekday())).fg(Color::Cyan),
+                    Cell::new(off.join(", ")).fg(Color::Yellow),
+                ]);
+            }
+            println!("\n{}", tr!("free_days.fewest", fewest).bold().yellow());
+            println!("{table}");
+        }
+    }
+    println!();
+}
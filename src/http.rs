@@ -0,0 +1,91 @@
+use crate::config::NetworkConfig;
+use crate::error::ConzeError;
+use crate::i18n::tr;
+use reqwest::{Certificate, Client, Proxy};
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 15;
+const DEFAULT_RETRIES: u32 = 3;
+const FIRST_BACKOFF: Duration = Duration::from_millis(500);
+
+/// An HTTP client shared by every download of one invocation. Clones share
+/// the same connection pool.
+#[derive(Clone)]
+pub struct HttpClient {
+    client: Client,
+    retries: u32,
+}
+
+impl HttpClient {
+    /// Builds the client from the `[network]` settings. Without a proxy in
+    /// the config, the `HTTPS_PROXY`, `HTTP_PROXY` and `NO_PROXY` variables
+    /// are used.
+    pub fn new(network: &NetworkConfig) -> Result<Self, ConzeError> {
+        let timeout = Duration::from_secs(network.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS));
+        let mut builder = Client::builder()
+            .timeout(timeout)
+            .connect_timeout(timeout.min(Duration::from_secs(5)))
+            .user_agent(concat!("conze/", env!("CARGO_PKG_VERSION")));
+
+        if let Some(proxy) = &network.proxy {
+            let proxy = Proxy::all(proxy)
+                .map_err(|e| ConzeError::ConfigError(tr!("error.invalid_proxy", proxy, e)))?;
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(path) = &network.ca_bundle {
+            let certificates = std::fs::read(path)
+                .map_err(|e| e.to_string())
+                .and_then(|pem| Certificate::from_pem_bundle(&pem).map_err(|e| e.to_string()))
+                .map_err(|e| {
+                    ConzeError::ConfigError(tr!("error.invalid_ca_bundle", path.display(), e))
+                })?;
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        let client = builder
+            .build()
+            .map_err(|e| ConzeError::ConfigError(tr!("error.generic", e)))?;
+        Ok(HttpClient {
+            client,
+            retries: network.retries.unwrap_or(DEFAULT_RETRIES),
+        })
+    }
+
+    /// Downloads a document, retrying transient failures with an exponential
+    /// backoff.
+    pub async fn get_text(&self, url: &str) -> Result<String, reqwest::Error> {
+        let mut backoff = FIRST_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match self.try_get_text(url).await {
+                Err(e) if attempt < self.retries && is_transient(&e) => {
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_get_text(&self, url: &str) -> Result<String, reqwest::Error> {
+        self.client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await
+    }
+}
+
+/// Whether trying again later may succeed.
+fn is_transient(error: &reqwest::Error) -> bool {
+    match error.status() {
+        Some(status) => status.is_server_error() || status.as_u16() == 429,
+        None => error.is_timeout() || error.is_connect() || error.is_request(),
+    }
+}
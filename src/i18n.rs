@@ -0,0 +1,1148 @@
+use chrono::Weekday;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    French,
+    Kreol,
+}
+
+impl Language {
+    /// Parses a language name or a locale such as `fr_FR.UTF-8`.
+    pub fn from_code(code: &str) -> Option<Language> {
+        let code = code.trim().to_lowercase();
+        let prefix = code.split(['_', '-', '.', '@']).next().unwrap_or_default();
+        match prefix {
+            "en" | "english" | "c" | "posix" => Some(Language::English),
+            "fr" | "french" | "francais" | "français" => Some(Language::French),
+            "mfe" | "kreol" | "kreol morisien" | "morisien" => Some(Language::Kreol),
+            _ => None,
+        }
+    }
+
+    /// The language of the first locale variable that is set.
+    pub fn from_env() -> Option<Language> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Language::from_code(&value))
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::Kreol => "mfe",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Language::English => 0,
+            Language::French => 1,
+            Language::Kreol => 2,
+        }
+    }
+}
+
+/// The index of the current language, see `Language::index`.
+static LANGUAGE: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the language of every message. It is set once from the user config
+/// and again when the project or environment change it.
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language.index(), Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::French,
+        2 => Language::Kreol,
+        _ => Language::English,
+    }
+}
+
+/// The message for `key` in the current language, falling back to English.
+pub fn t(key: &str) -> &'static str {
+    match MESSAGES.iter().find(|(k, _)| *k == key) {
+        Some((_, texts)) => {
+            let text = texts[language().index()];
+            if text.is_empty() {
+                texts[0]
+            } else {
+                text
+            }
+        }
+        None => panic!("missing message: {}", key),
+    }
+}
+
+/// Replaces each `{}` of `template` with the next argument.
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut parts = template.split("{}");
+    if let Some(first) = parts.next() {
+        result.push_str(first);
+    }
+    for part in parts {
+        if let Some(arg) = args.next() {
+            result.push_str(&arg.to_string());
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+/// The message for `key` with its placeholders filled, in plain ASCII with
+/// `--ascii`.
+pub fn message(key: &str, args: &[&dyn Display]) -> String {
+    let template = t(key);
+    if crate::style::ascii() {
+        fill(&crate::style::plain(template), args)
+    } else {
+        fill(template, args)
+    }
+}
+
+/// Translates a message, filling in its `{}` placeholders.
+macro_rules! tr {
+    ($key:expr) => {
+        $crate::i18n::message($key, &[])
+    };
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::message($key, &[$(&$arg as &dyn std::fmt::Display),+])
+    };
+}
+pub(crate) use tr;
+
+const WEEKDAYS: [[&str; 3]; 7] = [
+    ["Monday", "lundi", "Lindi"],
+    ["Tuesday", "mardi", "Mardi"],
+    ["Wednesday", "mercredi", "Merkredi"],
+    ["Thursday", "jeudi", "Zedi"],
+    ["Friday", "vendredi", "Vandredi"],
+    ["Saturday", "samedi", "Samdi"],
+    ["Sunday", "dimanche", "Dimans"],
+];
+
+const MONTHS: [[&str; 3]; 12] = [
+    ["January", "janvier", "Zanvie"],
+    ["February", "février", "Fevriye"],
+    ["March", "mars", "Mars"],
+    ["April", "avril", "Avril"],
+    ["May", "mai", "Me"],
+    ["June", "juin", "Zin"],
+    ["July", "juillet", "Zilye"],
+    ["August", "août", "Out"],
+    ["September", "septembre", "Septam"],
+    ["October", "octobre", "Oktob"],
+    ["November", "novembre", "Novam"],
+    ["December", "décembre", "Desam"],
+];
+
+pub fn weekday_name(weekday: Weekday) -> &'static str {
+    WEEKDAYS[weekday.num_days_from_monday() as usize][language().index()]
+}
+
+/// Two-letter weekday name for the calendar header.
+pub fn weekday_short(weekday: Weekday) -> String {
+    weekday_name(weekday).chars().take(2).collect()
+}
+
+pub fn month_name(month: u32) -> &'static str {
+    MONTHS[(month - 1) as usize][language().index()]
+}
+
+/// Messages by key, in English, French and Mauritian Kreol. An empty
+/// translation falls back to English.
+const MESSAGES: &[(&str, [&str; 3])] = &[
+    ("holiday.tentative", ["{} (tentative)", "{} (provisoire)", "{} (pa ankor konfirme)"]),
+    ("holiday.projected", ["{} (projected)", "{} (estimé)", "{} (estime)"]),
+    ("kind.public", ["public", "férié", "piblik"]),
+    ("kind.bank", ["bank", "bancaire", "labank"]),
+    ("kind.optional", ["optional", "facultatif", "opsionel"]),
+    ("kind.observance", ["observance", "commémoration", "komemorasion"]),
+    ("kind.school", ["school", "scolaire", "lekol"]),
+    ("kind.leave", ["leave", "congé", "konze"]),
+    ("leave.label_note", ["Leave: {}", "Congé : {}", "Konze: {}"]),
+    ("leave.label", ["Leave", "Congé", "Konze"]),
+    (
+        "leave.none",
+        [
+            "🏖️  No leave booked.",
+            "🏖️  Aucun congé posé.",
+            "🏖️  Pena okenn konze reserve.",
+        ],
+    ),
+    ("leave.title", ["🏖️  Booked leave", "🏖️  Congés posés", "🏖️  Konze reserve"]),
+    (
+        "leave.total",
+        [
+            "Total leave days: {}",
+            "Total des jours de congé : {}",
+            "Total zour konze: {}",
+        ],
+    ),
+    ("header.date", ["Date", "Date", "Dat"]),
+    ("header.day", ["Day", "Jour", "Zour"]),
+    ("header.note", ["Note", "Note", "Not"]),
+    ("header.holiday", ["Holiday", "Jour férié", "Konze piblik"]),
+    ("header.kind", ["Kind", "Type", "Tip"]),
+    ("header.observed", ["Observed", "Observé", "Obzerve"]),
+    ("header.country", ["Country", "Pays", "Pei"]),
+    (
+        "list.title",
+        [
+            "📅 Holidays for {} in {}",
+            "📅 Jours fériés {} en {}",
+            "📅 Konze piblik {} an {}",
+        ],
+    ),
+    ("list.total", ["Total holidays: {}", "Total des jours fériés : {}", "Total konze piblik: {}"]),
+    (
+        "list.total_leave",
+        [
+            "Booked leave: {} (total days off: {})",
+            "Congés posés : {} (total des jours off : {})",
+            "Konze reserve: {} (total zour lib: {})",
+        ],
+    ),
+    ("bridge.header.holiday_dates", ["Holiday\nDates", "Dates\nfériées", "Dat\nkonze"]),
+    ("bridge.header.holiday_days", ["Holiday\nDays", "Jours\nfériés", "Zour\nkonze"]),
+    ("bridge.header.holiday_names", ["Holiday\nNames", "Noms des\njours fériés", "Non\nkonze"]),
+    ("bridge.header.bridge_dates", ["Bridge\nDates", "Dates\nde pont", "Dat\npon"]),
+    ("bridge.header.bridge_days", ["Bridge\nDays", "Jours\nde pont", "Zour\npon"]),
+    (
+        "bridge.title_month",
+        [
+            "🌉 Bridge days for {} {} ({}):",
+            "🌉 Ponts pour {} {} ({}) :",
+            "🌉 Pon pou {} {} ({}):",
+        ],
+    ),
+    (
+        "bridge.title_year",
+        [
+            "🌉 Bridge days for the year {} ({}):",
+            "🌉 Ponts pour l'année {} ({}) :",
+            "🌉 Pon pou lane {} ({}):",
+        ],
+    ),
+    (
+        "bridge.found",
+        [
+            "🎯 Found {} bridge opportunities!",
+            "🎯 {} ponts possibles !",
+            "🎯 {} pon posib!",
+        ],
+    ),
+    ("bridge.none", ["😢 No bridge days found.", "😢 Aucun pont trouvé.", "😢 Pena okenn pon."]),
+    (
+        "bridge.total_month",
+        [
+            "📅 Total holidays for this month: {}",
+            "📅 Total des jours fériés ce mois-ci : {}",
+            "📅 Total konze piblik sa mwa la: {}",
+        ],
+    ),
+    (
+        "bridge.total_year",
+        [
+            "📅 Total holidays for this year: {}",
+            "📅 Total des jours fériés cette année : {}",
+            "📅 Total konze piblik sa lane la: {}",
+        ],
+    ),
+    ("holiday.observed", ["{} (observed)", "{} (observé)", "{} (obzerve)"]),
+    (
+        "team.read_failed",
+        [
+            "Failed to read team file at {}",
+            "Impossible de lire le fichier d'équipe {}",
+            "Pa kapav lir fisie lekip {}",
+        ],
+    ),
+    (
+        "team.parse_failed",
+        [
+            "Failed to parse team file",
+            "Impossible d'analyser le fichier d'équipe",
+            "Pa kapav konpran fisie lekip",
+        ],
+    ),
+    ("header.off", ["Off", "Absents", "Lib"]),
+    ("team.title", ["👥 Who is off", "👥 Qui est absent", "👥 Kisannla pa travay"]),
+    (
+        "team.everyone_working",
+        [
+            "Everyone is working.",
+            "Tout le monde travaille.",
+            "Tou dimounn pe travay.",
+        ],
+    ),
+    (
+        "free_days.title",
+        [
+            "🗓️  Working days in {} {} shared by {}:",
+            "🗓️  Jours ouvrés en {} {} communs à {} :",
+            "🗓️  Zour travay an {} {} ki pareil pou {}:",
+        ],
+    ),
+    (
+        "free_days.none",
+        [
+            "😢 No working day shared by every country.",
+            "😢 Aucun jour ouvré commun à tous les pays.",
+            "😢 Pena okenn zour travay ki pareil pou tou pei.",
+        ],
+    ),
+    (
+        "free_days.found",
+        [
+            "🎯 Found {} shared working days!",
+            "🎯 {} jours ouvrés communs !",
+            "🎯 {} zour travay an komin!",
+        ],
+    ),
+    ("header.off_in", ["Off in", "Férié en", "Konze dan"]),
+    (
+        "free_days.fewest",
+        [
+            "Days with the fewest countries off ({}):",
+            "Jours avec le moins de pays fériés ({}) :",
+            "Zour kot mwins pei an konze ({}):",
+        ],
+    ),
+    (
+        "diff.title",
+        [
+            "🔍 Holidays of {} and {} in {}",
+            "🔍 Jours fériés de {} et {} en {}",
+            "🔍 Konze piblik {} ek {} an {}",
+        ],
+    ),
+    ("diff.shared", ["🤝 Shared holidays", "🤝 Jours fériés communs", "🤝 Konze piblik an komin"]),
+    (
+        "diff.moved",
+        [
+            "🔀 Same holiday, different dates",
+            "🔀 Même jour férié, dates différentes",
+            "🔀 Mem konze, lot dat",
+        ],
+    ),
+    ("diff.only", ["📌 Only in {}", "📌 Seulement en {}", "📌 Zis dan {}"]),
+    ("none", ["None", "Aucun", "Nanye"]),
+    (
+        "changes.title",
+        [
+            "📈 Holiday changes for {} from {} to {}",
+            "📈 Changements des jours fériés pour {} de {} à {}",
+            "📈 Sanzman dan bann zour ferye pou {} depi {} ziska {}",
+        ],
+    ),
+    ("changes.added", ["➕ Added", "➕ Ajoutés", "➕ Azoute"]),
+    ("changes.removed", ["➖ Removed", "➖ Retirés", "➖ Retire"]),
+    ("changes.shifted", ["🔀 Shifted", "🔀 Déplacés", "🔀 Deplase"]),
+    (
+        "changes.weekday_holidays",
+        [
+            "Weekday holidays",
+            "Jours fériés en semaine",
+            "Zour ferye dan lasemenn",
+        ],
+    ),
+    ("changes.bridges", ["Bridge opportunities", "Possibilités de pont", "Posibilite pon"]),
+    (
+        "long_weekends.title",
+        [
+            "🏖️  Long weekends for {} ({}):",
+            "🏖️  Longs week-ends pour {} ({}) :",
+            "🏖️  Long wikenn pou {} ({}):",
+        ],
+    ),
+    (
+        "long_weekends.none",
+        [
+            "😢 No long weekends found.",
+            "😢 Aucun long week-end trouvé.",
+            "😢 Pena okenn long wikenn.",
+        ],
+    ),
+    (
+        "long_weekends.found",
+        [
+            "🎯 Found {} long weekends, {} days off without taking leave!",
+            "🎯 {} longs week-ends trouvés, {} jours de repos sans prendre de congé !",
+            "🎯 {} long wikenn trouve, {} zour repo san pran konze!",
+        ],
+    ),
+    ("long_weekends.range", ["{} to {}", "{} à {}", "{} ziska {}"]),
+    ("header.start", ["Start", "Début", "Koumansman"]),
+    ("header.end", ["End", "Fin", "Fin"]),
+    ("header.days", ["Days", "Jours", "Zour"]),
+    ("header.holidays", ["Holidays", "Jours fériés", "Zour ferye"]),
+    ("header.year", ["Year", "Année", "Lane"]),
+    ("header.on_weekends", ["On weekends", "Le week-end", "Dan wikenn"]),
+    ("header.weekday_holidays", ["Weekday holidays", "Fériés en semaine", "Ferye dan lasemenn"]),
+    ("header.long_weekends", ["Long weekends", "Longs week-ends", "Long wikenn"]),
+    ("header.longest_break", ["Longest break", "Plus longue pause", "Pli long repo"]),
+    ("header.bridges", ["Bridges", "Ponts", "Pon"]),
+    (
+        "stats.title",
+        [
+            "📊 Holiday statistics for {} from {} to {}",
+            "📊 Statistiques des jours fériés pour {} de {} à {}",
+            "📊 Statistik zour ferye pou {} depi {} ziska {}",
+        ],
+    ),
+    ("stats.days", ["{} days", "{} jours", "{} zour"]),
+    (
+        "stats.projected",
+        [
+            "* projected from built-in rules",
+            "* calculé à partir des règles intégrées",
+            "* kalkile depi bann reg intern",
+        ],
+    ),
+    (
+        "stats.per_year",
+        [
+            "Weekday holidays per year",
+            "Jours fériés en semaine par année",
+            "Zour ferye dan lasemenn par lane",
+        ],
+    ),
+    (
+        "stats.per_weekday",
+        [
+            "Holidays per day of the week",
+            "Jours fériés par jour de la semaine",
+            "Zour ferye par zour lasemenn",
+        ],
+    ),
+    (
+        "stats.best",
+        [
+            "🏆 Best year: {} ({} weekday holidays, {} long weekends)",
+            "🏆 Meilleure année : {} ({} fériés en semaine, {} longs week-ends)",
+            "🏆 Pli bon lane: {} ({} ferye dan lasemenn, {} long wikenn)",
+        ],
+    ),
+    (
+        "stats.worst",
+        [
+            "😢 Worst year: {} ({} weekday holidays, {} long weekends)",
+            "😢 Pire année : {} ({} fériés en semaine, {} longs week-ends)",
+            "😢 Pli move lane: {} ({} ferye dan lasemenn, {} long wikenn)",
+        ],
+    ),
+    (
+        "search.title",
+        [
+            "🔎 Holidays matching '{}':",
+            "🔎 Jours fériés correspondant à « {} » :",
+            "🔎 Zour ferye ki koresponn '{}':",
+        ],
+    ),
+    (
+        "search.none",
+        [
+            "😢 No holidays found.",
+            "😢 Aucun jour férié trouvé.",
+            "😢 Pena okenn zour ferye.",
+        ],
+    ),
+    ("search.total", ["Total matches: {}", "Total des résultats : {}", "Total rezilta: {}"]),
+    (
+        "error.unsupported_country",
+        [
+            "Unsupported country code: {}",
+            "Code pays non pris en charge : {}",
+            "Kod pei pa siporte: {}",
+        ],
+    ),
+    (
+        "error.available_countries",
+        [
+            "Available countries are:",
+            "Les pays disponibles sont :",
+            "Bann pei disponib se:",
+        ],
+    ),
+    (
+        "error.fetch_failed",
+        [
+            "Failed to fetch holiday data for {}",
+            "Impossible de récupérer les jours fériés pour {}",
+            "Pa finn kapav gagn bann zour ferye pou {}",
+        ],
+    ),
+    (
+        "error.no_data",
+        [
+            "No holiday data available for {}",
+            "Aucune donnée de jours fériés pour {}",
+            "Pena done zour ferye pou {}",
+        ],
+    ),
+    (
+        "error.no_data_year",
+        [
+            "No holiday data available for {} in {}",
+            "Aucune donnée de jours fériés pour {} en {}",
+            "Pena done zour ferye pou {} an {}",
+        ],
+    ),
+    (
+        "error.invalid_kind",
+        [
+            "Invalid holiday kind '{}'. Use one of: {}.",
+            "type de jour férié « {} » invalide. Utilisez l'un de : {}.",
+            "Kalite zour ferye '{}' pa valid. Servi enn ant: {}.",
+        ],
+    ),
+    (
+        "error.invalid_month",
+        [
+            "Invalid month '{}'. Use a number (1-12), a month name in English, French, Kreol or Afrikaans (may, mai, me, mei), a month of a year (2026-05, may 2026, 2026-W18) or a relative month (this month, next, last, +3m).",
+            "mois « {} » invalide. Utilisez un nombre (1-12), un nom de mois en anglais, français, kreol ou afrikaans (may, mai, me, mei), un mois d'une année (2026-05, mai 2026, 2026-W18) ou un mois relatif (this month, next, last, +3m).",
+            "Mwa '{}' pa valid. Servi enn nimero (1-12), nom enn mwa an angle, franse, kreol ouswa afrikaans (may, mai, me, mei), enn mwa dan enn lane (2026-05, me 2026, 2026-W18) ouswa enn mwa relatif (this month, next, last, +3m).",
+        ],
+    ),
+    (
+        "error.invalid_date",
+        [
+            "Invalid date '{}'. Use YYYY-MM-DD, DD-MM-YYYY or a relative day (today, tomorrow, +3d).",
+            "date « {} » invalide. Utilisez AAAA-MM-JJ, JJ-MM-AAAA ou un jour relatif (today, tomorrow, +3d).",
+            "Dat '{}' pa valid. Servi AAAA-MM-ZZ, ZZ-MM-AAAA ouswa enn zour relatif (today, tomorrow, +3d).",
+        ],
+    ),
+    (
+        "error.invalid_year",
+        [
+            "Invalid year '{}'. Use a year (2026) or a relative year (this year, next year, -1y).",
+            "année « {} » invalide. Utilisez une année (2026) ou une année relative (this year, next year, -1y).",
+            "Lane '{}' pa valid. Servi enn lane (2026) ouswa enn lane relatif (this year, next year, -1y).",
+        ],
+    ),
+    (
+        "error.invalid_year_range",
+        [
+            "Invalid year '{}'. Use a year (2026, next year) or a range (2026..2027).",
+            "année « {} » invalide. Utilisez une année (2026, next year) ou une plage (2026..2027).",
+            "Lane '{}' pa valid. Servi enn lane (2026, next year) ouswa enn interval (2026..2027).",
+        ],
+    ),
+    (
+        "error.invalid_years",
+        [
+            "Invalid years '{}'. Use a year (2026, next year) or a range (2020..2030).",
+            "années « {} » invalides. Utilisez une année (2026, next year) ou une plage (2020..2030).",
+            "Lane '{}' pa valid. Servi enn lane (2026, next year) ouswa enn interval (2020..2030).",
+        ],
+    ),
+    (
+        "error.invalid_pattern",
+        [
+            "Invalid search pattern '{}': {}",
+            "motif de recherche « {} » invalide : {}",
+            "Model rechers '{}' pa valid: {}",
+        ],
+    ),
+    ("error.generic", ["Error: {}", "Erreur : {}", "Erer: {}"]),
+    (
+        "error.invalid_command",
+        [
+            "Invalid command. Use 'bridge' or 'calendar'.",
+            "Commande invalide. Utilisez « bridge » ou « calendar ».",
+            "Komand pa valid. Servi 'bridge' ouswa 'calendar'.",
+        ],
+    ),
+    (
+        "error.invalid_config_command",
+        [
+            "Invalid config command. Use 'get', 'set', 'unset', 'list', 'show', 'edit', 'path' or 'reset', or '--default-country' to set a new default country.",
+            "Commande config invalide. Utilisez « get », « set », « unset », « list », « show », « edit », « path » ou « reset », ou « --default-country » pour changer le pays par défaut.",
+            "Komand config pa valid. Servi 'get', 'set', 'unset', 'list', 'show', 'edit', 'path' ouswa 'reset', ouswa '--default-country' pou sanz pei par defo.",
+        ],
+    ),
+    (
+        "error.invalid_leave_command",
+        [
+            "Invalid leave command. Use 'add', 'remove' or 'list'.",
+            "Commande leave invalide. Utilisez « add », « remove » ou « list ».",
+            "Komand leave pa valid. Servi 'add', 'remove' ouswa 'list'.",
+        ],
+    ),
+    (
+        "error.invalid_team_command",
+        [
+            "Invalid team command. Use 'calendar' or 'next'.",
+            "Commande team invalide. Utilisez « calendar » ou « next ».",
+            "Komand team pa valid. Servi 'calendar' ouswa 'next'.",
+        ],
+    ),
+    (
+        "config.default_country_set",
+        [
+            "Default country set to: {}",
+            "Pays par défaut défini sur : {}",
+            "Pei par defo aster: {}",
+        ],
+    ),
+    ("config.default_country", ["Default country: {}", "Pays par défaut : {}", "Pei par defo: {}"]),
+    ("config.language_set", ["Language set to: {}", "Langue définie sur : {}", "Lang aster: {}"]),
+    ("config.language", ["Language: {}", "Langue : {}", "Lang: {}"]),
+    ("leave.booked", ["Leave booked on {}", "Congé posé le {}", "Konze reserve pou {}"]),
+    ("leave.removed", ["Leave removed on {}", "Congé retiré le {}", "Konze retire pou {}"]),
+    (
+        "leave.not_booked",
+        [
+            "No leave booked on {}",
+            "Aucun congé posé le {}",
+            "Pena konze reserve pou {}",
+        ],
+    ),
+    (
+        "team.example",
+        [
+            "Describe your team in {}, for example:",
+            "Décrivez votre équipe dans {}, par exemple :",
+            "Dekrir ou lekip dan {}, par egzanp:",
+        ],
+    ),
+    (
+        "projected.note",
+        [
+            "⚠️  No data available for year {} in {}. Showing projected holidays.",
+            "⚠️  Aucune donnée pour l'année {} en {}. Affichage des jours fériés calculés.",
+            "⚠️  Pena done pou lane {} dan {}. Nou montre bann zour ferye kalkile.",
+        ],
+    ),
+    (
+        "projected.missing",
+        [
+            "⚠️  No data available for year {} in {}.",
+            "⚠️  Aucune donnée pour l'année {} en {}.",
+            "⚠️  Pena done pou lane {} dan {}.",
+        ],
+    ),
+    (
+        "projected.available_years",
+        [
+            "📅 Available years for {}: {} to {}",
+            "📅 Années disponibles pour {} : {} à {}",
+            "📅 Bann lane disponib pou {}: {} ziska {}",
+        ],
+    ),
+    (
+        "projected.shown",
+        [
+            "🔮 Projected holidays computed from built-in rules are shown instead.",
+            "🔮 Les jours fériés calculés à partir des règles intégrées sont affichés à la place.",
+            "🔮 Nou montre bann zour ferye kalkile depi bann reg intern plito.",
+        ],
+    ),
+    ("and", [" and ", " et ", " ek "]),
+    (
+        "pun.1",
+        [
+            "No holidays found for the year {}... looks like we'll have to bridge the gap to next year!",
+            "Aucun jour férié trouvé pour l'année {}... il faudra faire le pont jusqu'à l'année prochaine !",
+            "Pena zour ferye pou lane {}... nou pou bizin fer pon ziska lane prosenn!",
+        ],
+    ),
+    (
+        "pun.2",
+        [
+            "No holidays found for the year {}... you'll have to find another bridge to escape!",
+            "Aucun jour férié trouvé pour l'année {}... il faudra trouver un autre pont pour s'évader !",
+            "Pena zour ferye pou lane {}... ou pou bizin trouv enn lot pon pou sape!",
+        ],
+    ),
+    (
+        "pun.3",
+        [
+            "No holidays found for the year {}... guess it's time to build a new bridge to take a break!",
+            "Aucun jour férié trouvé pour l'année {}... il est temps de construire un nouveau pont pour souffler !",
+            "Pena zour ferye pou lane {}... ler pou konstrir enn nouvo pon pou pran poz!",
+        ],
+    ),
+    (
+        "pun.4",
+        [
+            "No holidays found for the year {}... looks like the holiday bridge is under construction!",
+            "Aucun jour férié trouvé pour l'année {}... le pont des vacances est en travaux !",
+            "Pena zour ferye pou lane {}... pon vakans pe konstrir ankor!",
+        ],
+    ),
+    (
+        "pun.5",
+        [
+            "No holidays found for the year {}... looks like all bridges to time off are closed!",
+            "Aucun jour férié trouvé pour l'année {}... tous les ponts vers le repos sont fermés !",
+            "Pena zour ferye pou lane {}... tou bann pon ver repo ferme!",
+        ],
+    ),
+    (
+        "pun.6",
+        [
+            "No holidays found for the year {}... it’s a long road with no bridges in sight!",
+            "Aucun jour férié trouvé pour l'année {}... une longue route sans aucun pont en vue !",
+            "Pena zour ferye pou lane {}... enn long semin san okenn pon!",
+        ],
+    ),
+    (
+        "pun.7",
+        [
+            "No holidays found for the year {}... guess the bridge to holidays has been washed away!",
+            "Aucun jour férié trouvé pour l'année {}... le pont vers les vacances a été emporté !",
+            "Pena zour ferye pou lane {}... lapli finn anmenn pon vakans!",
+        ],
+    ),
+    (
+        "error.month_not_in_year",
+        [
+            "The month '{}' is not in {}.",
+            "Le mois « {} » n'est pas en {}.",
+            "Mwa '{}' pa dan {}.",
+        ],
+    ),
+    (
+        "error.no_config_dir",
+        [
+            "Failed to find the configuration directory",
+            "Impossible de trouver le dossier de configuration",
+            "Pa finn kapav trouv dosie konfigirasion",
+        ],
+    ),
+    (
+        "error.invalid_proxy",
+        [
+            "Invalid proxy '{}': {}",
+            "Proxy « {} » invalide : {}",
+            "Proxy '{}' pa valid: {}",
+        ],
+    ),
+    (
+        "error.invalid_ca_bundle",
+        [
+            "Failed to load the CA bundle {}: {}",
+            "Impossible de charger le fichier de certificats {} : {}",
+            "Pa finn kapav sarz fisie sertifika {}: {}",
+        ],
+    ),
+    ("validate.error", ["error", "erreur", "erer"]),
+    ("validate.warning", ["warning", "avertissement", "avertisman"]),
+    (
+        "validate.ok",
+        [
+            "✅ {}: no problems found",
+            "✅ {} : aucun problème trouvé",
+            "✅ {}: pena okenn problem",
+        ],
+    ),
+    (
+        "validate.summary",
+        [
+            "{}: {} errors, {} warnings",
+            "{} : {} erreurs, {} avertissements",
+            "{}: {} erer, {} avertisman",
+        ],
+    ),
+    ("validate.line_column", ["line {}, column {}", "ligne {}, colonne {}", "lign {}, kolonn {}"]),
+    (
+        "validate.invalid_json",
+        [
+            "not valid JSON: {}",
+            "JSON invalide : {}",
+            "pa enn JSON valid: {}",
+        ],
+    ),
+    (
+        "validate.not_object",
+        [
+            "expected an object mapping years to holidays",
+            "un objet associant les années aux jours fériés est attendu",
+            "ti atann enn obze ki asosie bann lane ar zour ferye",
+        ],
+    ),
+    (
+        "validate.invalid_year_key",
+        [
+            "'{}' is not a year",
+            "« {} » n'est pas une année",
+            "'{}' pa enn lane",
+        ],
+    ),
+    (
+        "validate.year_out_of_range",
+        [
+            "year {} is outside {}..{}",
+            "l'année {} est hors de {}..{}",
+            "lane {} pa ant {}..{}",
+        ],
+    ),
+    (
+        "validate.not_list",
+        [
+            "expected a list of holidays",
+            "une liste de jours fériés est attendue",
+            "ti atann enn lis zour ferye",
+        ],
+    ),
+    (
+        "validate.not_holiday",
+        [
+            "expected a holiday object",
+            "un objet jour férié est attendu",
+            "ti atann enn obze zour ferye",
+        ],
+    ),
+    ("validate.missing_field", ["missing \"{}\"", "« {} » manquant", "\"{}\" manke"]),
+    (
+        "validate.not_text",
+        [
+            "\"{}\" is not text",
+            "« {} » n'est pas du texte",
+            "\"{}\" pa enn text",
+        ],
+    ),
+    ("validate.empty_name", ["the name is empty", "le nom est vide", "non-la vid"]),
+    (
+        "validate.invalid_date",
+        [
+            "\"{}\" is {}, not a YYYY-MM-DD date",
+            "« {} » vaut {}, pas une date AAAA-MM-JJ",
+            "\"{}\" li {}, pa enn dat AAAA-MM-ZZ",
+        ],
+    ),
+    ("validate.wrong_year", ["{} is not in {}", "{} n'est pas en {}", "{} pa dan {}"]),
+    ("validate.unknown_kind", ["unknown kind {}", "type {} inconnu", "kalite {} inkoni"]),
+    (
+        "validate.duplicate_date",
+        [
+            "{} is already used by {}",
+            "{} est déjà utilisé par {}",
+            "{} deza servi par {}",
+        ],
+    ),
+    (
+        "validate.duplicate_name",
+        [
+            "\"{}\" is already used by {}",
+            "« {} » est déjà utilisé par {}",
+            "\"{}\" deza servi par {}",
+        ],
+    ),
+    (
+        "validate.soft_check",
+        [
+            "⚠️ The {} holiday data has {} problems; run `conze validate` for details.",
+            "⚠️ Les jours fériés {} contiennent {} problèmes ; lancez `conze validate` pour les détails.",
+            "⚠️ Done zour ferye {} ena {} problem; lans `conze validate` pou plis detay.",
+        ],
+    ),
+    (
+        "error.invalid_data",
+        [
+            "The {} holiday data has {} errors",
+            "Les jours fériés {} contiennent {} erreurs",
+            "Done zour ferye {} ena {} erer",
+        ],
+    ),
+    (
+        "error.read_file",
+        [
+            "Could not read {}: {}",
+            "Impossible de lire {} : {}",
+            "Pa finn kapav lir {}: {}",
+        ],
+    ),
+    ("header.code", ["Code", "Code", "Kod"]),
+    ("header.years", ["Years", "Années", "Lane"]),
+    (
+        "countries.title",
+        [
+            "🌍 Supported countries",
+            "🌍 Pays pris en charge",
+            "🌍 Bann pei ki disponib",
+        ],
+    ),
+    ("countries.total", ["Total countries: {}", "Total des pays : {}", "Total pei: {}"]),
+    (
+        "validate.missing_year",
+        [
+            "listed in the country manifest but missing from the data",
+            "listée dans le manifeste des pays mais absente des données",
+            "lor lis manifest pei me pa dan done",
+        ],
+    ),
+    (
+        "error.manifest_fetch_failed",
+        [
+            "Failed to download the country manifest {}: {}",
+            "Impossible de télécharger le manifeste des pays {} : {}",
+            "Pa finn kapav telesarz manifest pei {}: {}",
+        ],
+    ),
+    (
+        "error.invalid_manifest",
+        [
+            "Invalid country manifest {}: {}",
+            "Manifeste des pays {} invalide : {}",
+            "Manifest pei {} pa valid: {}",
+        ],
+    ),
+    (
+        "error.manifest_empty",
+        [
+            "it lists no countries",
+            "il ne liste aucun pays",
+            "li pa lis okenn pei",
+        ],
+    ),
+    (
+        "error.manifest_code",
+        [
+            "'{}' is not a two-letter country code",
+            "« {} » n'est pas un code pays à deux lettres",
+            "'{}' pa enn kod pei de let",
+        ],
+    ),
+    (
+        "error.manifest_duplicate",
+        [
+            "{} is listed twice",
+            "{} est listé deux fois",
+            "{} lor lis de fwa",
+        ],
+    ),
+    ("config.set", ["{} set to: {}", "{} défini sur : {}", "{} aster: {}"]),
+    (
+        "config.unset",
+        [
+            "{} is back to its default",
+            "{} revient à sa valeur par défaut",
+            "{} retourn lor so valer par defo",
+        ],
+    ),
+    (
+        "config.saved",
+        [
+            "Configuration saved in {}",
+            "Configuration enregistrée dans {}",
+            "Konfigirasion anrezistre dan {}",
+        ],
+    ),
+    (
+        "config.reset",
+        [
+            "Configuration reset to the defaults",
+            "Configuration remise aux valeurs par défaut",
+            "Konfigirasion remet lor valer par defo",
+        ],
+    ),
+    (
+        "config.ignoring",
+        [
+            "⚠️ Ignoring the configuration: {}",
+            "⚠️ Configuration ignorée : {}",
+            "⚠️ Konfigirasion inyore: {}",
+        ],
+    ),
+    (
+        "config.expected_country",
+        [
+            "a two-letter country code, e.g. MU",
+            "un code pays à deux lettres, p. ex. MU",
+            "enn kod pei de let, par egzanp MU",
+        ],
+    ),
+    ("config.expected_language", ["en, fr or mfe", "en, fr ou mfe", "en, fr ouswa mfe"]),
+    (
+        "config.expected_manifest",
+        [
+            "a URL or an existing file",
+            "une URL ou un fichier existant",
+            "enn URL ouswa enn fisie ki egziste",
+        ],
+    ),
+    (
+        "config.expected_seconds",
+        [
+            "a number of seconds above 0",
+            "un nombre de secondes supérieur à 0",
+            "enn nomb segonn plis ki 0",
+        ],
+    ),
+    (
+        "config.expected_count",
+        [
+            "a whole number, e.g. 3",
+            "un nombre entier, p. ex. 3",
+            "enn nomb antie, par egzanp 3",
+        ],
+    ),
+    (
+        "config.expected_url",
+        [
+            "a URL, e.g. http://proxy.example.com:3128",
+            "une URL, p. ex. http://proxy.example.com:3128",
+            "enn URL, par egzanp http://proxy.example.com:3128",
+        ],
+    ),
+    ("config.expected_file", ["an existing file", "un fichier existant", "enn fisie ki egziste"]),
+    (
+        "error.config_invalid_value",
+        [
+            "Invalid value '{}' for {}: expected {}",
+            "Valeur « {} » invalide pour {} : {} attendu",
+            "Valer '{}' pa valid pou {}: ti atann {}",
+        ],
+    ),
+    (
+        "error.config_unknown_key",
+        [
+            "Unknown setting '{}'. The settings are: {}",
+            "Paramètre « {} » inconnu. Les paramètres sont : {}",
+            "Paramet '{}' inkoni. Bann paramet se: {}",
+        ],
+    ),
+    (
+        "error.invalid_config_file",
+        [
+            "The configuration in {} cannot be used: {}. Fix it with `conze config edit`, or start over with `conze config reset`.",
+            "La configuration de {} est inutilisable : {}. Corrigez-la avec `conze config edit`, ou repartez de zéro avec `conze config reset`.",
+            "Konfigirasion dan {} pa kapav servi: {}. Koriz li avek `conze config edit`, ouswa rekomanse avek `conze config reset`.",
+        ],
+    ),
+    (
+        "error.write_file",
+        [
+            "Could not write {}: {}",
+            "Impossible d'écrire {} : {}",
+            "Pa finn kapav ekrir {}: {}",
+        ],
+    ),
+    (
+        "error.editor_failed",
+        [
+            "Could not edit the configuration with '{}': {}",
+            "Impossible de modifier la configuration avec « {} » : {}",
+            "Pa finn kapav modifie konfigirasion avek '{}': {}",
+        ],
+    ),
+    (
+        "config.expected_data_url",
+        [
+            "a URL or an existing directory",
+            "une URL ou un dossier existant",
+            "enn URL ouswa enn repertwar ki egziste",
+        ],
+    ),
+    ("config.expected_bool", ["true or false", "true ou false", "true ouswa false"]),
+    ("error.invalid_env", ["${}: {}", "${} : {}", "${}: {}"]),
+    (
+        "error.offline_manifest",
+        [
+            "Cannot download the country manifest {} while offline",
+            "Impossible de télécharger le manifeste des pays {} hors ligne",
+            "Pa kapav telesarz manifest pei {} san konneksion",
+        ],
+    ),
+    ("leave.closure_label", ["Closed: {}", "Fermé : {}", "Ferme: {}"]),
+    (
+        "error.invalid_project_file",
+        [
+            "Invalid project settings in {}: {}",
+            "Paramètres de projet invalides dans {} : {}",
+            "Paramet proze pa valid dan {}: {}",
+        ],
+    ),
+    ("config.profile", ["Profile: {} ({})", "Profil : {} ({})", "Profil: {} ({})"]),
+    (
+        "config.profile_used",
+        [
+            "Default profile set to: {}",
+            "Profil par défaut défini sur : {}",
+            "Profil par defo aster: {}",
+        ],
+    ),
+    (
+        "config.expected_weekend",
+        [
+            "the weekend must have fewer than 7 days",
+            "le week-end doit compter moins de 7 jours",
+            "wikenn-la bizin ena mwins ki 7 zour",
+        ],
+    ),
+    (
+        "error.unknown_profile",
+        [
+            "Unknown profile '{}'. The profiles are: {}",
+            "Profil « {} » inconnu. Les profils sont : {}",
+            "Profil '{}' inkoni. Bann profil se: {}",
+        ],
+    ),
+    (
+        "error.invalid_profile",
+        [
+            "Invalid profile '{}': {}",
+            "Profil « {} » invalide : {}",
+            "Profil '{}' pa valid: {}",
+        ],
+    ),
+    (
+        "error.invalid_holiday_file",
+        [
+            "Invalid holiday file {}: {}",
+            "Fichier de jours fériés {} invalide : {}",
+            "Fisie zour ferye {} pa valid: {}",
+        ],
+    ),
+    (
+        "config.read_failed",
+        [
+            "Failed to read config file at {}",
+            "Impossible de lire le fichier de configuration {}",
+            "Pa kapav lir fisie konfigirasion {}",
+        ],
+    ),
+    (
+        "config.parse_failed",
+        [
+            "Failed to parse config file",
+            "Impossible d'analyser le fichier de configuration",
+            "Pa kapav konpran fisie konfigirasion",
+        ],
+    ),
+    (
+        "leave.read_failed",
+        [
+            "Failed to read leave file at {}",
+            "Impossible de lire le fichier de congés {}",
+            "Pa kapav lir fisie konze {}",
+        ],
+    ),
+    (
+        "leave.parse_failed",
+        [
+            "Failed to parse leave file",
+            "Impossible d'analyser le fichier de congés",
+            "Pa kapav konpran fisie konze",
+        ],
+    ),
+    (
+        "validate.json_eof",
+        [
+            "not valid JSON: the file ends too early",
+            "JSON invalide : le fichier se termine trop tôt",
+            "pa enn JSON valid: fisie la fini tro boner",
+        ],
+    ),
+];
@@ -0,0 +1,157 @@
+use crate::i18n::{tr, weekday_name};
+use crate::table::apply_style;
+use crate::Holiday;
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Leave {
+    pub date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// A day the whole team is off, from the project's `.conze.toml`,
+    /// rather than booked leave.
+    #[serde(skip)]
+    pub closure: bool,
+}
+
+impl Leave {
+    pub fn closure(date: NaiveDate, name: String) -> Self {
+        Leave {
+            date,
+            note: Some(name),
+            closure: true,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match &self.note {
+            Some(note) if self.closure => tr!("leave.closure_label", note),
+            Some(note) => tr!("leave.label_note", note),
+            None => tr!("leave.label"),
+        }
+    }
+
+    /// A stand-in holiday so booked leave counts as a non-working day.
+    pub fn as_holiday(&self) -> Holiday {
+        Holiday {
+            name: self.label(),
+            date: self.date,
+            kind: None,
+            observed: None,
+            projected: false,
+            tentative: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LeaveBook {
+    #[serde(default)]
+    pub leave: Vec<Leave>,
+    /// Project closure days, counted as days off but never saved.
+    #[serde(skip)]
+    closures: Vec<Leave>,
+}
+
+impl LeaveBook {
+    /// Loads the booked leave, treating a missing file as no leave at all.
+    pub fn load(leave_path: &Path) -> Result<Self> {
+        if !leave_path.exists() {
+            return Ok(LeaveBook::default());
+        }
+        let leave = std::fs::read_to_string(leave_path)
+            .with_context(|| tr!("leave.read_failed", format!("{:?}", leave_path)))?;
+        let leave: LeaveBook = toml::from_str(&leave).with_context(|| tr!("leave.parse_failed"))?;
+        Ok(leave)
+    }
+
+    pub fn save(&self, leave_path: &Path) -> Result<()> {
+        let toml_string = toml::to_string(self)?;
+        std::fs::create_dir_all(leave_path.parent().unwrap())?;
+        std::fs::write(leave_path, toml_string)?;
+        Ok(())
+    }
+
+    /// Books a day of leave, replacing the note if the day was already booked.
+    pub fn add(&mut self, date: NaiveDate, note: Option<String>) {
+        self.leave.retain(|l| l.date != date);
+        self.leave.push(Leave {
+            date,
+            note,
+            closure: false,
+        });
+        self.leave.sort_by_key(|l| l.date);
+    }
+
+    /// Removes a booked day, returning whether it was booked.
+    pub fn remove(&mut self, date: NaiveDate) -> bool {
+        let before = self.leave.len();
+        self.leave.retain(|l| l.date != date);
+        self.leave.len() != before
+    }
+
+    pub fn add_closures(&mut self, closures: Vec<Leave>) {
+        self.closures.extend(closures);
+    }
+
+    /// The booked leave of `year`.
+    pub fn booked_for_year(&self, year: i32) -> Vec<Leave> {
+        self.leave
+            .iter()
+            .filter(|l| l.date.year() == year)
+            .cloned()
+            .collect()
+    }
+
+    /// Every day off of `year`: booked leave and closures, in order.
+    pub fn for_year(&self, year: i32) -> Vec<Leave> {
+        let mut days: Vec<Leave> = self
+            .leave
+            .iter()
+            .chain(
+                self.closures
+                    .iter()
+                    .filter(|c| !self.leave.iter().any(|l| l.date == c.date)),
+            )
+            .filter(|l| l.date.year() == year)
+            .cloned()
+            .collect();
+        days.sort_by_key(|l| l.date);
+        days
+    }
+}
+
+pub fn list_leave(leave: &[Leave]) {
+    if leave.is_empty() {
+        println!("\n{}", tr!("leave.none").bold().yellow());
+        return;
+    }
+
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new(tr!("header.date")).fg(Color::Blue),
+            Cell::new(tr!("header.day")).fg(Color::Blue),
+            Cell::new(tr!("header.note")).fg(Color::Blue),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(100);
+    apply_style(&mut table);
+
+    for day in leave {
+        table.add_row(vec![
+            Cell::new(day.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+            Cell::new(weekday_name(day.date.weekday())).fg(Color::Cyan),
+            Cell::new(day.note.clone().unwrap_or_default()).fg(Color::Magenta),
+        ]);
+    }
+
+    println!("\n{}", tr!("leave.title").bold().yellow());
+    println!("{table}");
+    println!("\n{}", tr!("leave.total", leave.len()).bold().blue());
+}
@@ -1,51 +1,92 @@
-use crate::Holiday;
+use crate::i18n::{tr, weekday_name};
+use crate::leave::Leave;
+use crate::table::apply_style;
+use crate::{Holiday, HolidayKind};
 use chrono::Datelike;
 use colored::Colorize;
 use comfy_table::{Cell, Color, ContentArrangement, Table};
 
-pub fn list_holidays(holidays: &[Holiday], country: &str, year: i32) {
+pub fn list_holidays(holidays: &[Holiday], leave: &[Leave], country: &str, year: i32) {
     let mut table = Table::new();
     table
         .set_header(vec![
-            Cell::new("Date").fg(Color::Blue),
-            Cell::new("Day").fg(Color::Blue),
-            Cell::new("Holiday").fg(Color::Blue),
+            Cell::new(tr!("header.date")).fg(Color::Blue),
+            Cell::new(tr!("header.day")).fg(Color::Blue),
+            Cell::new(tr!("header.holiday")).fg(Color::Blue),
+            Cell::new(tr!("header.kind")).fg(Color::Blue),
+            Cell::new(tr!("header.observed")).fg(Color::Blue),
         ])
-        .load_preset(comfy_table::presets::UTF8_FULL)
-        .apply_modifier(comfy_table::modifiers::UTF8_ROUND_CORNERS)
         .set_content_arrangement(ContentArrangement::Dynamic)
         .set_width(100);
+    apply_style(&mut table);
 
+    // Holidays and leave in date order, holidays first on the same day
+    let mut rows = Vec::new();
     for holiday in holidays.iter().filter(|h| h.date.year() == year) {
-        table.add_row(vec![
-            Cell::new(holiday.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
-            Cell::new(weekday_to_string(holiday.date.weekday())).fg(Color::Cyan),
-            Cell::new(&holiday.name).fg(Color::Green),
-        ]);
+        rows.push((
+            holiday.date,
+            vec![
+                Cell::new(holiday.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+                Cell::new(weekday_name(holiday.date.weekday())).fg(Color::Cyan),
+                Cell::new(holiday.display_name()).fg(kind_color(holiday.kind())),
+                Cell::new(holiday.kind().label()).fg(kind_color(holiday.kind())),
+                Cell::new(observed_to_string(holiday)).fg(Color::Cyan),
+            ],
+        ));
+    }
+    for day in leave.iter().filter(|l| l.date.year() == year) {
+        rows.push((
+            day.date,
+            vec![
+                Cell::new(day.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+                Cell::new(weekday_name(day.date.weekday())).fg(Color::Cyan),
+                Cell::new(day.label()).fg(LEAVE_COLOR),
+                Cell::new(tr!("kind.leave")).fg(LEAVE_COLOR),
+                Cell::new(""),
+            ],
+        ));
+    }
+    rows.sort_by_key(|(date, _)| *date);
+    for (_, row) in rows {
+        table.add_row(row);
     }
 
-    println!(
-        "\n{}",
-        format!("📅 Holidays for {} in {}", country, year)
-            .bold()
-            .yellow()
-    );
+    println!("\n{}", tr!("list.title", country, year).bold().yellow());
     println!("{table}");
-    println!(
-        "\n{}",
-        format!("Total holidays: {}", holidays.len()).bold().blue()
-    );
+    println!("\n{}", tr!("list.total", holidays.len()).bold().blue());
+    if !leave.is_empty() {
+        println!(
+            "{}",
+            tr!(
+                "list.total_leave",
+                leave.len(),
+                holidays.len() + leave.len()
+            )
+            .bold()
+            .blue()
+        );
+    }
 }
 
-fn weekday_to_string(weekday: chrono::Weekday) -> String {
-    match weekday {
-        chrono::Weekday::Mon => "Monday",
-        chrono::Weekday::Tue => "Tuesday",
-        chrono::Weekday::Wed => "Wednesday",
-        chrono::Weekday::Thu => "Thursday",
-        chrono::Weekday::Fri => "Friday",
-        chrono::Weekday::Sat => "Saturday",
-        chrono::Weekday::Sun => "Sunday",
+fn observed_to_string(holiday: &Holiday) -> String {
+    match holiday.observed {
+        Some(date) if date != holiday.date => format!(
+            "{} ({})",
+            date.format("%d-%m-%Y"),
+            weekday_name(date.weekday())
+        ),
+        _ => String::new(),
+    }
+}
+
+const LEAVE_COLOR: Color = Color::Red;
+
+fn kind_color(kind: HolidayKind) -> Color {
+    match kind {
+        HolidayKind::Public => Color::Green,
+        HolidayKind::Bank => Color::Blue,
+        HolidayKind::Optional => Color::Yellow,
+        HolidayKind::Observance => Color::DarkGrey,
+        HolidayKind::School => Color::Magenta,
     }
-    .to_string()
 }
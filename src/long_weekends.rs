@@ -0,0 +1,124 @@
+use crate::i18n::{tr, weekday_short};
+use crate::puente::is_weekday;
+use crate::table::new_table;
+use crate::Holiday;
+use chrono::{Datelike, Duration, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, Color};
+use std::collections::BTreeSet;
+
+/// A break of consecutive non-working days that requires no leave.
+#[derive(Debug, Clone)]
+pub struct LongWeekend {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// The holidays that make the break longer than a plain weekend.
+    pub holidays: Vec<String>,
+}
+
+impl LongWeekend {
+    pub fn length(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+}
+
+/// Finds every break of at least three consecutive non-working days that
+/// contains one of the holidays of `year`.
+pub fn find_long_weekends(holidays: &[Holiday], year: i32) -> Vec<LongWeekend> {
+    let days_off: BTreeSet<NaiveDate> = holidays
+        .iter()
+        .filter(|h| h.kind().is_day_off())
+        .map(|h| h.observed_date())
+        .collect();
+    let is_day_off = |date: NaiveDate| !is_weekday(date) || days_off.contains(&date);
+
+    // Look a couple of days past the year so breaks around New Year are whole
+    let bound = |month, day, days| {
+        NaiveDate::from_ymd_opt(year, month, day)?.checked_add_signed(Duration::days(days))
+    };
+    let (Some(first), Some(last), Some(stop)) =
+        (bound(1, 1, -2), bound(12, 31, 2), bound(12, 31, 3))
+    else {
+        return Vec::new();
+    };
+
+    let mut long_weekends = Vec::new();
+    let mut run_start: Option<NaiveDate> = None;
+    for date in first.iter_days().take_while(|d| *d <= stop) {
+        if date <= last && is_day_off(date) {
+            run_start.get_or_insert(date);
+            continue;
+        }
+        let Some(start) = run_start.take() else {
+            continue;
+        };
+        let end = date.pred_opt().unwrap();
+        let run_holidays: Vec<String> = holidays
+            .iter()
+            .filter(|h| {
+                h.kind().is_day_off()
+                    && h.observed_date().year() == year
+                    && (start..=end).contains(&h.observed_date())
+            })
+            .map(|h| h.display_name())
+            .collect();
+        if (end - start).num_days() >= 2 && !run_holidays.is_empty() {
+            long_weekends.push(LongWeekend {
+                start,
+                end,
+                holidays: run_holidays,
+            });
+        }
+    }
+    long_weekends
+}
+
+pub fn print_long_weekends(long_weekends: &[LongWeekend], country_code: &str, years: &str) {
+    println!(
+        "\n{}",
+        tr!("long_weekends.title", years, country_code)
+            .bold()
+            .yellow()
+    );
+
+    if long_weekends.is_empty() {
+        println!("\n{}", tr!("long_weekends.none").bold().red());
+        println!();
+        return;
+    }
+
+    let mut table = new_table(vec![
+        tr!("header.start"),
+        tr!("header.end"),
+        tr!("header.days"),
+        tr!("header.holidays"),
+    ]);
+    for long_weekend in long_weekends {
+        table.add_row(vec![
+            Cell::new(format!(
+                "{} {}",
+                weekday_short(long_weekend.start.weekday()),
+                long_weekend.start.format("%d-%m-%Y")
+            ))
+            .fg(Color::Cyan),
+            Cell::new(format!(
+                "{} {}",
+                weekday_short(long_weekend.end.weekday()),
+                long_weekend.end.format("%d-%m-%Y")
+            ))
+            .fg(Color::Cyan),
+            Cell::new(long_weekend.length()).fg(Color::Green),
+            Cell::new(long_weekend.holidays.join("\n")).fg(Color::Green),
+        ]);
+    }
+    println!("{table}");
+
+    let total_days: i64 = long_weekends.iter().map(|l| l.length()).sum();
+    println!(
+        "\n{}",
+        tr!("long_weekends.found", long_weekends.len(), total_days)
+            .bold()
+            .green()
+    );
+    println!();
+}
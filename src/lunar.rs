@@ -0,0 +1,238 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+
+/// Mauritius local time offset from UTC, in hours.
+const MU_UTC_OFFSET: i64 = 4;
+/// China standard time offset from UTC, in hours.
+const CN_UTC_OFFSET: i64 = 8;
+
+/// Instant of the `k`-th new moon after January 2000, in UTC, following
+/// Meeus, Astronomical Algorithms, chapter 49 (largest periodic terms only).
+/// `None` when it falls outside the dates chrono can represent.
+fn new_moon(k: i64) -> Option<NaiveDateTime> {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let jde = 2451550.09766 + 29.530588861 * k + 0.00015437 * t * t - 0.000000150 * t.powi(3)
+        + 0.00000000073 * t.powi(4);
+
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t * t).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t * t).to_radians();
+    let f = (160.7108 + 390.67050284 * k - 0.0016118 * t * t).to_radians();
+    let om = (124.7746 - 1.56375588 * k + 0.0020672 * t * t).to_radians();
+
+    let correction = -0.40720 * mp.sin()
+        + 0.17241 * e * m.sin()
+        + 0.01608 * (2.0 * mp).sin()
+        + 0.01039 * (2.0 * f).sin()
+        + 0.00739 * e * (mp - m).sin()
+        - 0.00514 * e * (mp + m).sin()
+        + 0.00208 * e * e * (2.0 * m).sin()
+        - 0.00111 * (mp - 2.0 * f).sin()
+        - 0.00057 * (mp + 2.0 * f).sin()
+        + 0.00056 * e * (2.0 * mp + m).sin()
+        - 0.00042 * (3.0 * mp).sin()
+        + 0.00042 * e * (m + 2.0 * f).sin()
+        + 0.00038 * e * (m - 2.0 * f).sin()
+        - 0.00024 * e * (2.0 * mp - m).sin()
+        - 0.00017 * om.sin();
+
+    let j2000 = NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let seconds = ((jde + correction - 2451545.0) * 86400.0).round() as i64;
+    j2000.checked_add_signed(Duration::try_seconds(seconds)?)
+}
+
+/// First new moon whose local date, at `utc_offset` hours from UTC, falls
+/// between `from` and `to` inclusive. Returns the local date and time.
+fn new_moon_between(from: NaiveDate, to: NaiveDate, utc_offset: i64) -> Option<NaiveDateTime> {
+    let k0 =
+        ((from.year() - 2000) as f64 * 12.3685 + from.ordinal0() as f64 / 29.53).floor() as i64 - 1;
+    (k0..k0 + 3)
+        .filter_map(|k| new_moon(k)?.checked_add_signed(Duration::hours(utc_offset)))
+        .find(|moon| (from..=to).contains(&moon.date()))
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Chinese Spring Festival: the first new moon on or after January 21 in
+/// China standard time.
+pub fn spring_festival(year: i32) -> Option<NaiveDate> {
+    new_moon_between(ymd(year, 1, 21)?, ymd(year, 2, 20)?, CN_UTC_OFFSET).map(|m| m.date())
+}
+
+/// Maha Shivaratree: the night whose midnight falls in Krishna Chaturdashi,
+/// before the new moon that occurs while the sun is in Kumbha (sidereal
+/// Aquarius).
+pub fn maha_shivaratree(year: i32) -> Option<NaiveDate> {
+    let moon = new_moon_between(ymd(year, 2, 13)?, ymd(year, 3, 14)?, MU_UTC_OFFSET)?;
+    find_tithi_day(moon.date(), 24, 28)
+}
+
+/// Ougadi: the day whose sunrise falls in Chaitra Shukla Pratipada, after the
+/// new moon that occurs while the sun is in Meena (sidereal Pisces).
+pub fn ougadi(year: i32) -> Option<NaiveDate> {
+    let moon = new_moon_between(ymd(year, 3, 15)?, ymd(year, 4, 13)?, MU_UTC_OFFSET)?;
+    find_tithi_day(moon.date(), 6, 0)
+}
+
+/// Ganesh Chaturthi: the day whose midday period, from 11:30 in India
+/// (10:00 in Mauritius), falls in Bhadrapada Shukla Chaturthi, after the new
+/// moon that occurs while the sun is in Simha (sidereal Leo). Mauritius
+/// observes the holiday on the following day.
+pub fn ganesh_chaturthi(year: i32) -> Option<NaiveDate> {
+    let moon = new_moon_between(ymd(year, 8, 17)?, ymd(year, 9, 16)?, MU_UTC_OFFSET)?;
+    find_tithi_day(moon.date(), 10, 3)?.succ_opt()
+}
+
+/// Diwali: the day whose evening falls in Amavasya, at the new moon that
+/// occurs while the sun is in Tula (sidereal Libra).
+pub fn diwali(year: i32) -> Option<NaiveDate> {
+    let moon = new_moon_between(ymd(year, 10, 17)?, ymd(year, 11, 16)?, MU_UTC_OFFSET)?;
+    find_tithi_day(moon.date(), 18, 29)
+}
+
+/// First day around `new_moon` on which the tithi at `hour` (Mauritius time)
+/// is `tithi`, counting 0 for Shukla Pratipada up to 29 for Amavasya. When
+/// the tithi is skipped at that hour, the day on which it occurred is used.
+fn find_tithi_day(new_moon: NaiveDate, hour: u32, tithi: u32) -> Option<NaiveDate> {
+    (-3..=6)
+        .filter_map(|offset| new_moon.checked_add_signed(Duration::days(offset)))
+        .find(|date| {
+            let utc = date
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .checked_add_signed(Duration::hours(hour as i64 - MU_UTC_OFFSET));
+            utc.is_some_and(|utc| (tithi_at(utc) + 30 - tithi) % 30 <= 2)
+        })
+}
+
+/// Tithi (lunar day) at a UTC instant, from the Moon-Sun elongation.
+fn tithi_at(utc: NaiveDateTime) -> u32 {
+    (elongation(utc) / 12.0).floor() as u32 % 30
+}
+
+/// Elongation of the Moon from the Sun in degrees, using the low-precision
+/// series of the Astronomical Almanac (accurate to a fraction of a degree).
+fn elongation(utc: NaiveDateTime) -> f64 {
+    let j2000 = NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let d = (utc - j2000).num_seconds() as f64 / 86400.0;
+
+    let g = (357.529 + 0.98560028 * d).to_radians();
+    let sun = 280.459 + 0.98564736 * d + 1.915 * g.sin() + 0.020 * (2.0 * g).sin();
+
+    let mean_elongation = (297.850 + 12.19074912 * d).to_radians();
+    let moon_anomaly = (134.963 + 13.06499295 * d).to_radians();
+    let latitude_argument = (93.272 + 13.22935024 * d).to_radians();
+    let moon = 218.316 + 13.17639648 * d + 6.289 * moon_anomaly.sin()
+        - 1.274 * (moon_anomaly - 2.0 * mean_elongation).sin()
+        + 0.658 * (2.0 * mean_elongation).sin()
+        + 0.214 * (2.0 * moon_anomaly).sin()
+        - 0.186 * g.sin()
+        - 0.114 * (2.0 * latitude_argument).sin();
+
+    (moon - sun).rem_euclid(360.0)
+}
+
+/// Eid-ul-Fitr: 1 Shawwal in the tabular Islamic calendar. The official date
+/// depends on the sighting of the crescent and may differ by a day.
+pub fn eid_ul_fitr(year: i32) -> Option<NaiveDate> {
+    let estimate = ((year - 622) as f64 * 33.0 / 32.0) as i64;
+    (estimate - 1..estimate + 2)
+        .filter_map(|islamic_year| islamic_to_gregorian(islamic_year, 10, 1))
+        .find(|date| date.year() == year)
+}
+
+fn islamic_to_gregorian(year: i64, month: i64, day: i64) -> Option<NaiveDate> {
+    let julian_day =
+        (11 * year + 3) / 30 + 354 * year + 30 * month - (month - 1) / 2 + day + 1948440 - 385;
+    ymd(2000, 1, 1)?.checked_add_signed(Duration::try_days(julian_day - 2451545)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks `rule` against the gazetted dates of the holiday in Mauritius.
+    fn check(rule: fn(i32) -> Option<NaiveDate>, expected: &[(i32, u32, u32)]) {
+        for &(year, month, day) in expected {
+            assert_eq!(rule(year), ymd(year, month, day), "{}", year);
+        }
+    }
+
+    #[test]
+    fn spring_festival_dates() {
+        check(
+            spring_festival,
+            &[(2023, 1, 22), (2024, 2, 10), (2025, 1, 29), (2026, 2, 17)],
+        );
+    }
+
+    #[test]
+    fn maha_shivaratree_dates() {
+        check(
+            maha_shivaratree,
+            &[(2023, 2, 18), (2024, 3, 8), (2025, 2, 26), (2026, 2, 15)],
+        );
+    }
+
+    #[test]
+    fn ougadi_dates() {
+        check(
+            ougadi,
+            &[(2023, 3, 22), (2024, 4, 9), (2025, 3, 30), (2026, 3, 19)],
+        );
+    }
+
+    #[test]
+    fn ganesh_chaturthi_dates() {
+        check(
+            ganesh_chaturthi,
+            &[(2023, 9, 20), (2024, 9, 8), (2025, 8, 28), (2026, 9, 15)],
+        );
+    }
+
+    #[test]
+    fn diwali_dates() {
+        check(
+            diwali,
+            &[
+                (2023, 11, 12),
+                (2024, 10, 31),
+                (2025, 10, 20),
+                (2026, 11, 8),
+            ],
+        );
+    }
+
+    #[test]
+    fn years_beyond_chrono_are_skipped() {
+        for year in [-300_000, 262_143, 300_000] {
+            assert_eq!(spring_festival(year), None);
+            assert_eq!(maha_shivaratree(year), None);
+            assert_eq!(diwali(year), None);
+            assert_eq!(eid_ul_fitr(year), None);
+        }
+    }
+
+    /// The tabular calendar may be a day off the sighting of the crescent.
+    #[test]
+    fn eid_ul_fitr_dates() {
+        for (year, month, day) in [(2023, 4, 22), (2024, 4, 11), (2025, 3, 31)] {
+            let official = ymd(year, month, day).unwrap();
+            let estimate = eid_ul_fitr(year).unwrap();
+            assert!(
+                (estimate - official).num_days().abs() <= 1,
+                "{}: {}",
+                year,
+                estimate
+            );
+        }
+    }
+}
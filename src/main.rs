@@ -1,27 +1,138 @@
 mod cal;
+mod changes;
 mod cli;
 mod config;
+mod countries;
+mod datasets;
+mod diff;
+mod error;
+mod free_days;
+mod http;
+mod i18n;
+mod leave;
 mod list;
+mod long_weekends;
+mod lunar;
+mod observed;
 mod parser;
 mod puente;
+mod rules;
+mod search;
+mod stats;
+mod style;
+mod table;
+mod team;
+mod validate;
 
 use chrono::{Datelike, Local, NaiveDate};
+use clap::ArgMatches;
 use colored::Colorize;
 use directories::ProjectDirs;
 use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::cal::print_calendar_comparison;
-use crate::config::Config;
-use crate::parser::parse_month;
+use crate::config::{Config, NetworkConfig, Profile, ProjectConfig};
+use crate::countries::CountryRegistry;
+use crate::datasets::Datasets;
+use crate::error::ConzeError;
+use crate::http::HttpClient;
+use crate::i18n::{tr, Language};
+use crate::leave::{Leave, LeaveBook};
+use crate::observed::apply_observed_dates;
+use crate::parser::{parse_kind, parse_month, parse_period, parse_year_range, Period};
 use crate::puente::print_puente_days;
+use crate::style::ColorChoice;
+use crate::team::Team;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum HolidayKind {
+    Public,
+    Bank,
+    #[serde(alias = "religious")]
+    Optional,
+    Observance,
+    School,
+}
+
+impl HolidayKind {
+    const ALL: [HolidayKind; 5] = [
+        HolidayKind::Public,
+        HolidayKind::Bank,
+        HolidayKind::Optional,
+        HolidayKind::Observance,
+        HolidayKind::School,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            HolidayKind::Public => "public",
+            HolidayKind::Bank => "bank",
+            HolidayKind::Optional => "optional",
+            HolidayKind::Observance => "observance",
+            HolidayKind::School => "school",
+        }
+    }
+
+    /// Whether the holiday is a guaranteed day off for everyone.
+    fn is_day_off(&self) -> bool {
+        matches!(self, HolidayKind::Public | HolidayKind::Bank)
+    }
+
+    fn label(&self) -> String {
+        match self {
+            HolidayKind::Public => tr!("kind.public"),
+            HolidayKind::Bank => tr!("kind.bank"),
+            HolidayKind::Optional => tr!("kind.optional"),
+            HolidayKind::Observance => tr!("kind.observance"),
+            HolidayKind::School => tr!("kind.school"),
+        }
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Holiday {
     name: String,
     date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kind: Option<HolidayKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    observed: Option<NaiveDate>,
+    /// Computed from the built-in rules rather than taken from the dataset.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    projected: bool,
+    /// The official date may still move, e.g. pending moon sighting.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    tentative: bool,
+}
+
+impl Holiday {
+    /// Entries without a kind in the dataset are public holidays.
+    fn kind(&self) -> HolidayKind {
+        self.kind.unwrap_or(HolidayKind::Public)
+    }
+
+    /// The day the holiday is actually taken off, after substitution rules.
+    fn observed_date(&self) -> NaiveDate {
+        self.observed.unwrap_or(self.date)
+    }
+
+    /// Whether both holidays are the same holiday, going by their names.
+    fn same_name(&self, other: &Holiday) -> bool {
+        self.name.trim().eq_ignore_ascii_case(other.name.trim())
+    }
+
+    /// The name with a marker for dates not taken from the dataset.
+    fn display_name(&self) -> String {
+        match (self.projected, self.tentative) {
+            (_, true) => tr!("holiday.tentative", self.name),
+            (true, false) => tr!("holiday.projected", self.name),
+            (false, false) => self.name.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -30,156 +141,399 @@ struct HolidaysByYear {
     years: HashMap<String, Vec<Holiday>>,
 }
 
+impl HolidaysByYear {
+    /// The years covered by the dataset, in order.
+    fn available_years(&self) -> Vec<i32> {
+        let mut years: Vec<i32> = self.years.keys().filter_map(|y| y.parse().ok()).collect();
+        years.sort();
+        years
+    }
+}
+
 #[derive(Debug)]
 struct CountryHolidays {
     country: String,
     holidays: Vec<Holiday>,
 }
 
-fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let proj_dirs =
-        ProjectDirs::from("", "", "conze").ok_or("Failed to get project directories")?;
-    Ok(proj_dirs.config_dir().join("config.toml"))
+fn get_config_dir() -> Result<PathBuf, ConzeError> {
+    let proj_dirs = ProjectDirs::from("", "", "conze")
+        .ok_or_else(|| ConzeError::ConfigError(tr!("error.no_config_dir")))?;
+    Ok(proj_dirs.config_dir().to_path_buf())
+}
+
+/// The `--config` file, else `$CONZE_CONFIG`, else `config.toml` in the
+/// config directory.
+fn get_config_path(matches: &ArgMatches) -> Result<PathBuf, ConzeError> {
+    if let Some(path) = matches.get_one::<PathBuf>("config-file") {
+        return Ok(path.clone());
+    }
+    match std::env::var_os("CONZE_CONFIG").filter(|path| !path.is_empty()) {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Ok(get_config_dir()?.join("config.toml")),
+    }
 }
 
-async fn fetch_holidays(url: &str) -> Result<HolidaysByYear, reqwest::Error> {
-    reqwest::Client::new().get(url).send().await?.json().await
+/// `leave.toml`, next to the configuration file in `config_dir`.
+fn get_leave_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("leave.toml")
+}
+
+/// `team.toml`, next to the configuration file in `config_dir`.
+fn get_team_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("team.toml")
+}
+
+/// Applies the profile, the project file found from the current directory,
+/// then the environment, over the user config, the `--profile` flag coming
+/// last. Returns the profile and the extra days off of the profile and the
+/// project.
+fn apply_overrides(
+    config: &mut Config,
+    flag: Option<&str>,
+    config_dir: &Path,
+) -> Result<(Option<Profile>, Vec<Leave>), ConzeError> {
+    let project = match std::env::current_dir()
+        .ok()
+        .and_then(|dir| ProjectConfig::find(&dir))
+    {
+        Some(path) => Some(ProjectConfig::load(&path)?),
+        None => None,
+    };
+
+    // A profile chosen in a file ranks with the user config, but one given
+    // with `--profile` takes precedence over the project and environment.
+    let mut profile = match flag {
+        Some(_) => None,
+        None => {
+            let name = project.as_ref().and_then(|p| p.setting("profile"));
+            config.apply_profile(name, config_dir)?
+        }
+    };
+    if let Some(project) = &project {
+        config.apply_project(project)?;
+    }
+    config.apply_env()?;
+    if flag.is_some() {
+        profile = config.apply_profile(flag, config_dir)?;
+    }
+    let mut days_off = match &profile {
+        Some(profile) => profile.load_holidays()?,
+        None => Vec::new(),
+    };
+    if let Some(project) = project {
+        days_off.extend(project.closures);
+    }
+    Ok((profile, days_off))
+}
+
+/// Sets a config key, also checking that a default country is supported.
+fn set_config(
+    config: &mut Config,
+    key: &str,
+    value: &str,
+    countries: &CountryRegistry,
+) -> Result<(), ConzeError> {
+    config.set(key, value)?;
+    if key == "default_country" && countries.get(&config.default_country).is_none() {
+        return Err(countries.unsupported(&config.default_country));
+    }
+    Ok(())
+}
+
+fn filter_kinds(holidays: &[Holiday], kinds: &[HolidayKind]) -> Vec<Holiday> {
+    holidays
+        .iter()
+        .filter(|holiday| kinds.contains(&holiday.kind()))
+        .cloned()
+        .collect()
+}
+
+/// Holidays of `year` from the dataset, falling back to the built-in rules
+/// when the dataset does not cover that year.
+fn holidays_for_year(data: &HolidaysByYear, country_code: &str, year: i32) -> Option<Vec<Holiday>> {
+    data.years
+        .get(&year.to_string())
+        .cloned()
+        .or_else(|| rules::project_holidays(country_code, year))
+}
+
+/// Holidays of `year` ready for display: projected if needed, filtered by
+/// kind and with substitution rules applied.
+fn prepare_holidays(
+    data: &HolidaysByYear,
+    country_code: &str,
+    year: i32,
+    kinds: &[HolidayKind],
+) -> Option<Vec<Holiday>> {
+    let holidays = holidays_for_year(data, country_code, year)?;
+    let mut holidays = filter_kinds(&holidays, kinds);
+    apply_observed_dates(country_code, &mut holidays);
+    Some(holidays)
+}
+
+fn print_projected_note(holidays: &[Holiday], country_code: &str, year: i32) {
+    if holidays.iter().any(|h| h.projected) {
+        println!(
+            "\n{}",
+            tr!("projected.note", year, country_code).bold().yellow()
+        );
+    }
+}
+
+/// The year given by the `name` argument, or `default` when it is not given.
+fn year_arg(
+    matches: &ArgMatches,
+    name: &str,
+    today: NaiveDate,
+    default: i32,
+) -> Result<i32, ConzeError> {
+    match matches.get_one::<String>(name) {
+        Some(y) => parse_period(y, today)
+            .map(|period| period.year())
+            .ok_or_else(|| ConzeError::InvalidInput(tr!("error.invalid_year", y))),
+        None => Ok(default),
+    }
+}
+
+/// The year and month given by the `year` and `month` arguments. A month
+/// given by name alone falls in the given year, or in the current one.
+fn month_args(matches: &ArgMatches, today: NaiveDate) -> Result<(i32, Option<u32>), ConzeError> {
+    let year = matches
+        .get_one::<String>("year")
+        .map(|_| year_arg(matches, "year", today, today.year()))
+        .transpose()?;
+    let Some(m) = matches.get_one::<String>("month") else {
+        return Ok((year.unwrap_or(today.year()), None));
+    };
+    if let Some(month) = parse_month(m) {
+        return Ok((year.unwrap_or(today.year()), Some(month)));
+    }
+
+    let period = parse_period(m, today)
+        .filter(|period| period.month().is_some())
+        .ok_or_else(|| ConzeError::InvalidInput(tr!("error.invalid_month", m)))?;
+    match year {
+        Some(year) if year != period.year() => Err(ConzeError::InvalidInput(tr!(
+            "error.month_not_in_year",
+            m,
+            year
+        ))),
+        _ => Ok((period.year(), period.month())),
+    }
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let current_year = Local::now().year();
-    let current_month = Local::now().month();
+async fn main() {
+    if let Err(error) = run().await {
+        eprintln!("{}", tr!("error.generic", error).red());
+        std::process::exit(error.exit_code());
+    }
+}
+
+async fn run() -> Result<(), ConzeError> {
+    let today = Local::now().date_naive();
+    let current_year = today.year();
+    let current_month = today.month();
     let matches = cli::cli().get_matches();
 
-    let urls = HashMap::from([
-        ("MU", "https://raw.githubusercontent.com/nicolasstrands/data-konzer/main/data/public-holidays-mu.json"),
-        ("ZA", "https://raw.githubusercontent.com/nicolasstrands/data-konzer/main/data/public-holidays-za.json"),
-        ("FR", "https://raw.githubusercontent.com/nicolasstrands/data-konzer/main/data/public-holidays-fr.json"),
-    ]);
+    let color = matches
+        .get_one::<String>("color")
+        .and_then(|name| ColorChoice::from_name(name))
+        .unwrap_or(ColorChoice::Auto);
+    style::init(color, matches.get_flag("ascii"));
 
-    let config_path = get_config_path()?;
-    let mut config = Config::load(&config_path).unwrap_or_else(|_| Config {
-        default_country: "MU".to_string(),
-    });
+    // `config` must still work with broken settings, so they can be fixed.
+    let config_command = matches.subcommand_matches("config");
+    let repairing_config = config_command
+        .and_then(ArgMatches::subcommand_name)
+        .is_some_and(|name| matches!(name, "edit" | "path" | "reset"));
+
+    let config_path = get_config_path(&matches)?;
+    i18n::set_language(
+        Config::language_in(&config_path)
+            .or_else(Language::from_env)
+            .unwrap_or(Language::English),
+    );
+    let mut saved_config = match Config::load(&config_path) {
+        Err(error) if repairing_config => {
+            eprintln!(
+                "{}",
+                tr!("config.ignoring", format!("{:#}", error).trim_end()).yellow()
+            );
+            Config::default()
+        }
+        config => config.map_err(|error| config::invalid_file(&config_path, error))?,
+    };
+
+    // Command-line flags take precedence over these, when given.
+    let mut config = saved_config.clone();
+    let profile_name = matches.get_one::<String>("profile").map(String::as_str);
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let (profile, days_off) = match apply_overrides(&mut config, profile_name, config_dir) {
+        Ok(overrides) => overrides,
+        Err(error) if config_command.is_some() => {
+            eprintln!("{}", tr!("config.ignoring", error).yellow());
+            (None, Vec::new())
+        }
+        Err(error) => return Err(error),
+    };
+    if let Some(weekend) = profile.as_ref().and_then(|p| p.weekend.clone()) {
+        puente::set_weekend(weekend);
+    }
+
+    let language = config
+        .language
+        .as_deref()
+        .and_then(Language::from_code)
+        .or_else(Language::from_env)
+        .unwrap_or(Language::English);
+    i18n::set_language(language);
+
+    let kinds = match matches.get_many::<String>("kinds") {
+        Some(values) => {
+            let mut kinds = Vec::new();
+            for value in values {
+                match parse_kind(value) {
+                    Some(kind) => kinds.push(kind),
+                    None => {
+                        return Err(ConzeError::InvalidInput(tr!(
+                            "error.invalid_kind",
+                            value,
+                            HolidayKind::ALL.map(|k| k.name()).join(", ")
+                        )));
+                    }
+                }
+            }
+            kinds
+        }
+        None => HolidayKind::ALL.to_vec(),
+    };
+
+    let client = match HttpClient::new(&config.network) {
+        Err(error) if config_command.is_some() => {
+            eprintln!("{}", tr!("config.ignoring", error).yellow());
+            HttpClient::new(&NetworkConfig::default())?
+        }
+        client => client?,
+    };
+    let offline = config.network.offline;
+    let mut countries =
+        match CountryRegistry::load(config.manifest.as_deref(), &client, offline).await {
+            Err(error) if config_command.is_some() => {
+                eprintln!("{}", tr!("config.ignoring", error).yellow());
+                CountryRegistry::load(None, &client, offline).await?
+            }
+            countries => countries?,
+        };
+    if let Some(data_url) = &config.data_url {
+        countries.rebase(data_url);
+    }
+    let mut datasets = Datasets::new(client, countries);
+    datasets.set_offline(offline);
+
+    let leave_path = match profile.as_ref().and_then(|p| p.leave.clone()) {
+        Some(path) => path,
+        None => get_leave_path(config_dir),
+    };
+    let mut leave_book = LeaveBook::load(&leave_path)?;
+    leave_book.add_closures(days_off);
 
     match matches.subcommand() {
         Some(("bridge", sub_matches)) => {
-            let month = sub_matches.get_one::<String>("month");
-            let year = sub_matches
-                .get_one::<String>("year")
-                .and_then(|y| y.parse::<i32>().ok())
-                .unwrap_or(current_year);
+            let (year, month) = month_args(sub_matches, today)?;
 
             let country_code = sub_matches
                 .get_one::<String>("country")
                 .map(|s| s.to_uppercase())
                 .unwrap_or_else(|| config.default_country.clone());
 
-            if let Some(url) = urls.get(country_code.as_str()) {
-                if let Ok(holidays_data) = fetch_holidays(url).await {
-                    if let Some(holidays) = holidays_data.years.get(&year.to_string()) {
-                        match month.and_then(|m| parse_month(&m)) {
-                            Some(month) => {
-                                let holidays_for_month: Vec<&Holiday> = holidays
-                                    .iter()
-                                    .filter(|holiday| holiday.date.month() == month)
-                                    .collect();
-                                print_puente_days(
-                                    Some(month),
-                                    year,
-                                    &holidays_for_month,
-                                    &country_code,
-                                );
-                            }
-                            None => {
-                                let holiday_refs: Vec<&Holiday> = holidays.iter().collect();
-                                print_puente_days(None, year, &holiday_refs, &country_code);
-                            }
-                        }
-                    } else {
-                        println!("{}", bridge_pun(year));
-                    }
-                } else {
-                    println!("Failed to fetch holiday data for {}", country_code);
+            let holidays_data = datasets.get(&country_code).await?;
+            let Some(holidays) = holidays_for_year(&holidays_data, &country_code, year) else {
+                println!("{}", bridge_pun(year));
+                return Err(ConzeError::NoDataForYear {
+                    country: country_code,
+                    year: Some(year),
+                });
+            };
+            print_projected_note(&holidays, &country_code, year);
+            let mut holidays = filter_kinds(&holidays, &kinds);
+            apply_observed_dates(&country_code, &mut holidays);
+            match month {
+                Some(month) => {
+                    let holidays_for_month: Vec<&Holiday> = holidays
+                        .iter()
+                        .filter(|holiday| holiday.observed_date().month() == month)
+                        .collect();
+                    print_puente_days(
+                        Some(month),
+                        year,
+                        &holidays_for_month,
+                        &leave_book.for_year(year),
+                        &country_code,
+                    );
+                }
+                None => {
+                    let holiday_refs: Vec<&Holiday> = holidays.iter().collect();
+                    print_puente_days(
+                        None,
+                        year,
+                        &holiday_refs,
+                        &leave_book.for_year(year),
+                        &country_code,
+                    );
                 }
-            } else {
-                println!("Unsupported country code: {}", country_code);
-                println!("Available countries are:");
-                println!("  - Mauritius (MU)\n  - South Africa (ZA)\n  - France (FR)");
             }
         }
 
         Some(("calendar", sub_matches)) => {
-            let month_input = sub_matches.get_one::<String>("month");
-            let year = sub_matches
-                .get_one::<String>("year")
-                .and_then(|y| y.parse::<i32>().ok())
-                .unwrap_or(current_year);
-
-            let month = match month_input {
-                Some(m) => match parse_month(m) {
-                    Some(parsed_month) => parsed_month,
-                    None => {
-                        println!("Error: Invalid month input. Please use a number (1-12) or a month name.");
-                        return Ok(());
-                    }
-                },
-                None => current_month,
-            };
+            let (year, month) = month_args(sub_matches, today)?;
+            let month = month.unwrap_or(current_month);
 
-            let compare_country = sub_matches.get_one::<String>("compare");
+            let compare_country = sub_matches
+                .get_one::<String>("compare")
+                .map(|s| s.to_uppercase());
 
             let mut country_holidays = Vec::new();
             let mut missing_data = Vec::new();
 
-            // Fetch default country holidays
-            if let Ok(default_holidays) =
-                fetch_holidays(urls[config.default_country.as_str()]).await
-            {
-                if let Some(holidays) = default_holidays.years.get(&year.to_string()) {
+            // The default country, then the comparison country if specified
+            let countries: Vec<&String> = std::iter::once(&config.default_country)
+                .chain(compare_country.as_ref())
+                .collect();
+            let country_data = datasets.get_all(&countries).await?;
+            for (country_code, country_data) in countries.iter().zip(&country_data) {
+                if let Some(holidays) = country_data.years.get(&year.to_string()) {
+                    let mut holidays = filter_kinds(holidays, &kinds);
+                    apply_observed_dates(country_code, &mut holidays);
                     country_holidays.push(CountryHolidays {
-                        country: config.default_country.clone(),
-                        holidays: holidays.clone(),
+                        country: country_code.to_string(),
+                        holidays,
                     });
                 } else {
-                    missing_data.push(&config.default_country);
-                }
-            }
-
-            // Fetch comparison country if specified
-            if let Some(country_code) = compare_country {
-                if let Some(url) = urls.get(country_code.as_str()) {
-                    if let Ok(country_data) = fetch_holidays(url).await {
-                        if let Some(holidays) = country_data.years.get(&year.to_string()) {
-                            country_holidays.push(CountryHolidays {
-                                country: country_code.to_string(),
-                                holidays: holidays.clone(),
-                            });
-                        } else {
-                            missing_data.push(country_code);
-                        }
+                    missing_data.push(country_code);
+                    if let Some(holidays) = rules::project_holidays(country_code, year) {
+                        let mut holidays = filter_kinds(&holidays, &kinds);
+                        apply_observed_dates(country_code, &mut holidays);
+                        country_holidays.push(CountryHolidays {
+                            country: country_code.to_string(),
+                            holidays,
+                        });
                     }
-                } else {
-                    println!("Unsupported country code: {}", country_code);
-                    println!("Available countries are:");
-                    println!("  - Mauritius (MU)\n  - South Africa (ZA)\n  - France (FR)");
-
-                    return Ok(());
                 }
             }
 
             if !missing_data.is_empty() {
                 println!(
                     "{}\n",
-                    format!(
-                        "⚠️  No data available for year {} in {}.",
+                    tr!(
+                        "projected.missing",
                         year,
                         missing_data
                             .iter()
                             .map(|s| s.as_str())
                             .collect::<Vec<&str>>()
-                            .join(" and ")
+                            .join(&tr!("and"))
                     )
                     .bold()
                     .yellow()
@@ -187,98 +541,553 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Print available years for each country with missing data
                 for country in missing_data {
-                    let url = urls.get(country.as_str()).unwrap();
-
-                    if let Ok(holiday_data) = fetch_holidays(url).await {
-                        let available_years: Vec<_> = holiday_data
-                            .years
-                            .keys()
-                            .map(|y| y.parse::<i32>().unwrap_or(0))
-                            .collect();
-
-                        if !available_years.is_empty() {
-                            let min_year = available_years.iter().min().unwrap();
-                            let max_year = available_years.iter().max().unwrap();
-                            println!(
-                                "{}",
-                                format!(
-                                    "📅 Available years for {}: {} to {}",
-                                    country, min_year, max_year
-                                )
+                    let available_years = datasets.get(country).await?.available_years();
+                    if let (Some(min_year), Some(max_year)) =
+                        (available_years.first(), available_years.last())
+                    {
+                        println!(
+                            "{}",
+                            tr!("projected.available_years", country, min_year, max_year)
                                 .bold()
                                 .blue()
-                            );
-                        }
+                        );
                     }
                 }
+                if country_holidays
+                    .iter()
+                    .any(|c| c.holidays.iter().any(|h| h.projected))
+                {
+                    println!("{}", tr!("projected.shown").bold().blue());
+                }
                 println!(); // Add a blank line for better formatting
             }
 
             if !country_holidays.is_empty() {
-                cal::print_calendar_comparison(month, year, &country_holidays);
+                cal::print_calendar_comparison(
+                    month,
+                    year,
+                    &country_holidays,
+                    &leave_book.for_year(year),
+                );
             }
         }
 
-        Some(("config", sub_matches)) => {
-            if let Some(default_country) = sub_matches.get_one::<String>("default-country") {
-                config.default_country = default_country.to_uppercase();
-                config.save(&config_path)?;
-                println!("Default country set to: {}", config.default_country);
-            } else if sub_matches.subcommand_matches("show").is_some() {
-                println!("Default country: {}", config.default_country);
+        Some(("config", sub_matches)) => match sub_matches.subcommand() {
+            Some(("get", get_matches)) => {
+                let key = get_matches.get_one::<String>("key").unwrap();
+                if let Some(value) = config.get(key)? {
+                    println!("{}", value);
+                }
+            }
+            Some(("set", set_matches)) => {
+                let key = set_matches.get_one::<String>("key").unwrap();
+                let value = set_matches.get_one::<String>("value").unwrap();
+                set_config(&mut saved_config, key, value, datasets.registry())?;
+                saved_config.save(&config_path)?;
+                let value = saved_config.get(key)?.unwrap_or_default();
+                println!("{}", tr!("config.set", key, value));
+            }
+            Some(("unset", unset_matches)) => {
+                let key = unset_matches.get_one::<String>("key").unwrap();
+                saved_config.unset(key)?;
+                saved_config.save(&config_path)?;
+                println!("{}", tr!("config.unset", key));
+            }
+            Some(("list", _)) => {
+                for key in config::KEYS {
+                    if let Some(value) = config.get(key)? {
+                        println!("{} = {}", key, value);
+                    }
+                }
+            }
+            Some(("edit", _)) => {
+                config::edit(&config_path)?;
+                println!("{}", tr!("config.saved", config_path.display()));
+            }
+            Some(("path", _)) => println!("{}", config_path.display()),
+            Some(("reset", _)) => {
+                if config_path.exists() {
+                    std::fs::remove_file(&config_path).map_err(|e| {
+                        ConzeError::ConfigError(tr!("error.write_file", config_path.display(), e))
+                    })?;
+                }
+                println!("{}", tr!("config.reset"));
+            }
+            Some(("use", use_matches)) => {
+                let name = use_matches.get_one::<String>("name").unwrap();
+                set_config(&mut saved_config, "profile", name, datasets.registry())?;
+                saved_config.save(&config_path)?;
+                println!("{}", tr!("config.profile_used", name));
+            }
+            Some(("show", _)) => {
+                if let (Some(name), Some(profile)) = (&config.profile, &profile) {
+                    let location = profile.location(&config.default_country);
+                    println!("{}", tr!("config.profile", name, location));
+                }
+                println!("{}", tr!("config.default_country", config.default_country));
+                println!("{}", tr!("config.language", i18n::language().code()));
+            }
+            _ => {
+                if let Some(default_country) = sub_matches.get_one::<String>("default-country") {
+                    set_config(
+                        &mut saved_config,
+                        "default_country",
+                        default_country,
+                        datasets.registry(),
+                    )?;
+                    saved_config.save(&config_path)?;
+                    println!(
+                        "{}",
+                        tr!("config.default_country_set", saved_config.default_country)
+                    );
+                } else if let Some(code) = sub_matches.get_one::<String>("language") {
+                    set_config(&mut saved_config, "language", code, datasets.registry())?;
+                    saved_config.save(&config_path)?;
+                    println!(
+                        "{}",
+                        tr!(
+                            "config.language_set",
+                            saved_config.get("language")?.unwrap_or_default()
+                        )
+                    );
+                } else {
+                    return Err(ConzeError::InvalidInput(tr!(
+                        "error.invalid_config_command"
+                    )));
+                }
+            }
+        },
+        Some(("leave", sub_matches)) => match sub_matches.subcommand() {
+            Some(("add", add_matches)) => {
+                let date_input = add_matches.get_one::<String>("date").unwrap();
+                let Some(Period::Day(date)) = parse_period(date_input, today) else {
+                    return Err(ConzeError::InvalidInput(tr!(
+                        "error.invalid_date",
+                        date_input
+                    )));
+                };
+                let note = add_matches.get_one::<String>("note").cloned();
+                leave_book.add(date, note);
+                leave_book.save(&leave_path)?;
+                println!("{}", tr!("leave.booked", date.format("%d-%m-%Y")));
+            }
+            Some(("remove", remove_matches)) => {
+                let date_input = remove_matches.get_one::<String>("date").unwrap();
+                let Some(Period::Day(date)) = parse_period(date_input, today) else {
+                    return Err(ConzeError::InvalidInput(tr!(
+                        "error.invalid_date",
+                        date_input
+                    )));
+                };
+                if leave_book.remove(date) {
+                    leave_book.save(&leave_path)?;
+                    println!("{}", tr!("leave.removed", date.format("%d-%m-%Y")));
+                } else {
+                    println!("{}", tr!("leave.not_booked", date.format("%d-%m-%Y")));
+                }
+            }
+            Some(("list", list_matches)) => match list_matches.get_one::<String>("year") {
+                Some(_) => {
+                    let year = year_arg(list_matches, "year", today, current_year)?;
+                    leave::list_leave(&leave_book.booked_for_year(year));
+                }
+                None => leave::list_leave(&leave_book.leave),
+            },
+            _ => {
+                return Err(ConzeError::InvalidInput(tr!("error.invalid_leave_command")));
+            }
+        },
+
+        Some(("team", sub_matches)) => {
+            let team_path = get_team_path(config_dir);
+            let team = Team::load(&team_path).map_err(|e| {
+                ConzeError::ConfigError(format!(
+                    "{:#}\n{}\n{}",
+                    e,
+                    tr!("team.example", format!("{:?}", team_path)),
+                    "  [members.alice]\n  country = \"MU\"\n\n  [members.bob]\n  country = \"FR\"\n  work_week = [\"mon\", \"tue\", \"wed\", \"thu\"]"
+                ))
+            })?;
+
+            let (years, month) = match sub_matches.subcommand() {
+                Some(("calendar", cal_matches)) => {
+                    let (year, month) = month_args(cal_matches, today)?;
+                    (vec![year], Some(month.unwrap_or(current_month)))
+                }
+                Some(("next", _)) => (vec![current_year, current_year + 1], None),
+                _ => {
+                    return Err(ConzeError::InvalidInput(tr!("error.invalid_team_command")));
+                }
+            };
+
+            let countries: Vec<String> = team.countries().into_iter().collect();
+            let datasets = datasets.get_all(&countries).await?;
+            let mut country_holidays = Vec::new();
+            for (country, holidays_data) in countries.into_iter().zip(datasets) {
+                let holidays = years
+                    .iter()
+                    .filter_map(|&y| prepare_holidays(&holidays_data, &country, y, &kinds))
+                    .flatten()
+                    .collect();
+                country_holidays.push(CountryHolidays {
+                    country: country.clone(),
+                    holidays,
+                });
+            }
+
+            match (sub_matches.subcommand(), month) {
+                (Some(("next", next_matches)), _) => {
+                    let count = *next_matches
+                        .get_one::<usize>("count")
+                        .expect("count has a default");
+                    team::print_team_next(
+                        Local::now().date_naive(),
+                        count,
+                        &team,
+                        &country_holidays,
+                    );
+                }
+                (_, Some(month)) => {
+                    team::print_team_calendar(month, years[0], &team, &country_holidays);
+                }
+                _ => {}
+            }
+        }
+
+        Some(("free-days", sub_matches)) => {
+            let (year, month) = month_args(sub_matches, today)?;
+            let month = month.unwrap_or(current_month);
+            let countries: Vec<String> = match sub_matches.get_many::<String>("countries") {
+                Some(values) => values.map(|c| c.to_uppercase()).collect(),
+                None => vec![config.default_country.clone()],
+            };
+
+            let datasets = datasets.get_all(&countries).await?;
+            let mut country_holidays = Vec::new();
+            for (country, holidays_data) in countries.iter().zip(datasets) {
+                match prepare_holidays(&holidays_data, country, year, &kinds) {
+                    Some(holidays) => {
+                        print_projected_note(&holidays, country, year);
+                        country_holidays.push(CountryHolidays {
+                            country: country.clone(),
+                            holidays,
+                        });
+                    }
+                    None => {
+                        return Err(ConzeError::NoDataForYear {
+                            country: country.clone(),
+                            year: Some(year),
+                        });
+                    }
+                }
+            }
+
+            free_days::print_free_days(
+                month,
+                year,
+                &country_holidays,
+                sub_matches.get_flag("fewest"),
+            );
+        }
+
+        Some(("diff", sub_matches)) => {
+            let year = year_arg(sub_matches, "year", today, current_year)?;
+            let first = sub_matches
+                .get_one::<String>("first")
+                .unwrap()
+                .to_uppercase();
+            let second = sub_matches
+                .get_one::<String>("second")
+                .unwrap()
+                .to_uppercase();
+
+            let datasets = datasets.get_all(&[&first, &second]).await?;
+            let mut both = Vec::new();
+            for (country, holidays_data) in [&first, &second].into_iter().zip(datasets) {
+                match prepare_holidays(&holidays_data, country, year, &kinds) {
+                    Some(holidays) => both.push(holidays),
+                    None => {
+                        return Err(ConzeError::NoDataForYear {
+                            country: country.clone(),
+                            year: Some(year),
+                        });
+                    }
+                }
+            }
+
+            let diff = diff::diff_holidays(&first, &both[0], &second, &both[1], year);
+            if sub_matches.get_flag("json") {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&diff).expect("the diff serializes to JSON")
+                );
             } else {
-                println!("Invalid config command. Use '--default-country' to set a new default country or 'show' to display the current configuration.");
+                for (country, holidays) in [&first, &second].iter().zip(&both) {
+                    print_projected_note(holidays, country, year);
+                }
+                diff::print_diff(&diff);
             }
         }
-        Some(("list", sub_matches)) => {
+
+        Some(("changes", sub_matches)) => {
+            let country_code = sub_matches
+                .get_one::<String>("country")
+                .map(|s| s.to_uppercase())
+                .unwrap_or_else(|| config.default_country.clone());
+            let to = year_arg(sub_matches, "to", today, current_year)?;
+            let from = year_arg(sub_matches, "from", today, to - 1)?;
+
+            let holidays_data = datasets.get(&country_code).await?;
+
+            // Only compare what the dataset says, never projected holidays
+            let mut years = Vec::new();
+            for year in [from, to] {
+                let Some(holidays) = holidays_data.years.get(&year.to_string()) else {
+                    return Err(ConzeError::NoDataForYear {
+                        country: country_code,
+                        year: Some(year),
+                    });
+                };
+                let mut holidays = filter_kinds(holidays, &kinds);
+                apply_observed_dates(&country_code, &mut holidays);
+                years.push(holidays);
+            }
+
+            let changes = changes::compare_years(from, &years[0], to, &years[1]);
+            changes::print_changes(&changes, &country_code);
+        }
+
+        Some(("stats", sub_matches)) => {
+            let country_code = sub_matches
+                .get_one::<String>("country")
+                .map(|s| s.to_uppercase())
+                .unwrap_or_else(|| config.default_country.clone());
+
+            let years = sub_matches
+                .get_one::<String>("years")
+                .map(|y| {
+                    parse_year_range(y, today)
+                        .ok_or_else(|| ConzeError::InvalidInput(tr!("error.invalid_years", y)))
+                })
+                .transpose()?;
+
+            let holidays_data = datasets.get(&country_code).await?;
+
+            // Default to every year of the dataset
+            let years = match years {
+                Some(years) => years,
+                None => {
+                    let available = holidays_data.available_years();
+                    match (available.first(), available.last()) {
+                        (Some(&min), Some(&max)) => min..=max,
+                        _ => current_year..=current_year,
+                    }
+                }
+            };
+
+            let stats: Vec<_> = years
+                .filter_map(|year| {
+                    prepare_holidays(&holidays_data, &country_code, year, &kinds)
+                        .map(|holidays| stats::year_stats(year, &holidays))
+                })
+                .collect();
+            if stats.is_empty() {
+                return Err(ConzeError::NoDataForYear {
+                    country: country_code,
+                    year: None,
+                });
+            }
+            stats::print_stats(&stats, &country_code);
+        }
+
+        Some(("long-weekends", sub_matches)) => {
             let country_code = sub_matches
                 .get_one::<String>("country")
                 .map(|s| s.to_uppercase())
                 .unwrap_or_else(|| config.default_country.clone());
+            let years = match sub_matches.get_one::<String>("year") {
+                Some(y) => parse_year_range(y, today)
+                    .ok_or_else(|| ConzeError::InvalidInput(tr!("error.invalid_year_range", y)))?,
+                None => current_year..=current_year,
+            };
+
+            let holidays_data = datasets.get(&country_code).await?;
 
-            let year = sub_matches
-                .get_one::<String>("year")
-                .and_then(|y| y.parse::<i32>().ok())
-                .unwrap_or(current_year);
-
-            if let Some(url) = urls.get(country_code.as_str()) {
-                if let Ok(holidays_data) = fetch_holidays(url).await {
-                    if let Some(holidays) = holidays_data.years.get(&year.to_string()) {
-                        list::list_holidays(holidays, &country_code, year);
-                    } else {
-                        println!("No holiday data available for {} in {}", country_code, year);
+            let mut long_weekends = Vec::new();
+            for year in years.clone() {
+                match prepare_holidays(&holidays_data, &country_code, year, &kinds) {
+                    Some(holidays) => {
+                        print_projected_note(&holidays, &country_code, year);
+                        long_weekends.extend(long_weekends::find_long_weekends(&holidays, year));
+                    }
+                    None => println!("{}", tr!("error.no_data_year", country_code, year)),
+                }
+            }
+
+            let label = if years.start() == years.end() {
+                years.start().to_string()
+            } else {
+                tr!("long_weekends.range", years.start(), years.end())
+            };
+            long_weekends::print_long_weekends(&long_weekends, &country_code, &label);
+        }
+
+        Some(("search", sub_matches)) => {
+            let pattern = sub_matches.get_one::<String>("pattern").unwrap();
+            let matcher = search::name_matcher(pattern)
+                .map_err(|e| ConzeError::InvalidInput(tr!("error.invalid_pattern", pattern, e)))?;
+
+            let mut countries: Vec<String> = match sub_matches.get_many::<String>("country") {
+                Some(values) => values.map(|c| c.to_uppercase()).collect(),
+                None => datasets.countries(),
+            };
+            countries.sort();
+            countries.dedup();
+
+            let years =
+                match sub_matches.get_one::<String>("year") {
+                    Some(y) => Some(parse_year_range(y, today).ok_or_else(|| {
+                        ConzeError::InvalidInput(tr!("error.invalid_year_range", y))
+                    })?),
+                    None if sub_matches.get_flag("all-years") => None,
+                    None => Some(current_year..=current_year),
+                };
+
+            let datasets = datasets.get_all(&countries).await?;
+            let mut results = Vec::new();
+            for (country, holidays_data) in countries.iter().zip(datasets) {
+                let years: Vec<i32> = match &years {
+                    Some(range) => range.clone().collect(),
+                    None => holidays_data.available_years(),
+                };
+                for year in years {
+                    if let Some(holidays) = prepare_holidays(&holidays_data, country, year, &kinds)
+                    {
+                        results.extend(
+                            search::search_holidays(&matcher, &holidays)
+                                .into_iter()
+                                .map(|h| (country.clone(), h.clone())),
+                        );
                     }
-                } else {
-                    println!("Failed to fetch holiday data for {}", country_code);
                 }
+            }
+
+            results.sort_by(|a, b| a.1.date.cmp(&b.1.date).then(a.0.cmp(&b.0)));
+            search::print_search_results(pattern, &results);
+        }
+
+        Some(("countries", _)) => {
+            let unlisted: Vec<String> = datasets
+                .registry()
+                .iter()
+                .filter(|country| country.years.is_empty())
+                .map(|country| country.code.clone())
+                .collect();
+            let unlisted_data = datasets.get_all(&unlisted).await?;
+
+            let registry = datasets.registry();
+            let countries: Vec<_> = registry
+                .iter()
+                .map(|country| {
+                    let years = match unlisted.iter().position(|c| *c == country.code) {
+                        Some(i) => unlisted_data[i].available_years(),
+                        None => country.years.clone(),
+                    };
+                    (country, years)
+                })
+                .collect();
+            countries::print_countries(&countries);
+        }
+
+        Some(("validate", sub_matches)) => {
+            let mut errors = Vec::new();
+            if let Some(path) = sub_matches.get_one::<PathBuf>("file") {
+                let text = std::fs::read_to_string(path).map_err(|e| {
+                    ConzeError::InvalidInput(tr!("error.read_file", path.display(), e))
+                })?;
+                let issues = validate::check(&text);
+                let source = path.display().to_string();
+                validate::print_report(&source, &issues);
+                errors.push((source, validate::error_count(&issues)));
             } else {
-                println!("Unsupported country code: {}", country_code);
-                println!("Available countries are:");
-                println!("  - Mauritius (MU)\n  - South Africa (ZA)\n  - France (FR)");
+                let mut countries: Vec<String> = match sub_matches.get_many::<String>("country") {
+                    Some(values) => values.map(|c| c.to_uppercase()).collect(),
+                    None => datasets.countries(),
+                };
+                countries.sort();
+                countries.dedup();
+
+                datasets.set_soft_check(false);
+                for country in countries {
+                    let available = match datasets.get(&country).await {
+                        Ok(holidays_data) => holidays_data.available_years(),
+                        Err(ConzeError::InvalidData { .. }) => Vec::new(),
+                        Err(error) => return Err(error),
+                    };
+                    let mut issues = datasets.issues(&country).to_vec();
+                    if let Some(listed) = datasets.registry().get(&country) {
+                        issues.extend(validate::check_years(&listed.years, &available));
+                    }
+                    validate::print_report(&country, &issues);
+                    errors.push((country, validate::error_count(&issues)));
+                }
+            }
+
+            if let Some((dataset, errors)) = errors.into_iter().find(|(_, errors)| *errors > 0) {
+                return Err(ConzeError::InvalidData { dataset, errors });
             }
         }
 
+        Some(("list", sub_matches)) => {
+            let country_code = sub_matches
+                .get_one::<String>("country")
+                .map(|s| s.to_uppercase())
+                .unwrap_or_else(|| config.default_country.clone());
+
+            let year = year_arg(sub_matches, "year", today, current_year)?;
+
+            let holidays_data = datasets.get(&country_code).await?;
+            let Some(holidays) = holidays_for_year(&holidays_data, &country_code, year) else {
+                return Err(ConzeError::NoDataForYear {
+                    country: country_code,
+                    year: Some(year),
+                });
+            };
+            print_projected_note(&holidays, &country_code, year);
+            let mut holidays = filter_kinds(&holidays, &kinds);
+            apply_observed_dates(&country_code, &mut holidays);
+            list::list_holidays(&holidays, &leave_book.for_year(year), &country_code, year);
+        }
+
         _ => {
             // Handle the case where no arguments are provided
             if matches.subcommand_name().is_none() {
                 let mut country_holidays = Vec::new();
 
                 // Fetch default country holidays
-                if let Ok(default_holidays) =
-                    fetch_holidays(urls[config.default_country.as_str()]).await
-                {
-                    if let Some(holidays) = default_holidays.years.get(&current_year.to_string()) {
-                        country_holidays.push(CountryHolidays {
-                            country: config.default_country.clone(),
-                            holidays: holidays.clone(),
-                        });
-                    }
+                let default_holidays = datasets.get(&config.default_country).await?;
+                if let Some(holidays) = prepare_holidays(
+                    &default_holidays,
+                    &config.default_country,
+                    current_year,
+                    &kinds,
+                ) {
+                    print_projected_note(&holidays, &config.default_country, current_year);
+                    country_holidays.push(CountryHolidays {
+                        country: config.default_country.clone(),
+                        holidays,
+                    });
                 }
 
                 // Print the calendar for the current month
-                print_calendar_comparison(current_month, current_year, &country_holidays);
+                print_calendar_comparison(
+                    current_month,
+                    current_year,
+                    &country_holidays,
+                    &leave_book.for_year(current_year),
+                );
             } else {
-                println!("Invalid command. Use 'bridge' or 'calendar'.");
+                return Err(ConzeError::InvalidInput(tr!("error.invalid_command")));
             }
         }
     }
@@ -289,13 +1098,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 fn bridge_pun(year: i32) -> String {
     // Create a list of pun lines
     let pun_lines = [
-        format!("No holidays found for the year {}... looks like we'll have to bridge the gap to next year!", year),
-        format!("No holidays found for the year {}... you'll have to find another bridge to escape!", year),
-        format!("No holidays found for the year {}... guess it's time to build a new bridge to take a break!", year),
-        format!("No holidays found for the year {}... looks like the holiday bridge is under construction!", year),
-        format!("No holidays found for the year {}... looks like all bridges to time off are closed!", year),
-        format!("No holidays found for the year {}... it’s a long road with no bridges in sight!", year),
-        format!("No holidays found for the year {}... guess the bridge to holidays has been washed away!", year),
+        tr!("pun.1", year),
+        tr!("pun.2", year),
+        tr!("pun.3", year),
+        tr!("pun.4", year),
+        tr!("pun.5", year),
+        tr!("pun.6", year),
+        tr!("pun.7", year),
     ];
 
     // Select a random pun line
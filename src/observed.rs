@@ -0,0 +1,59 @@
+use crate::Holiday;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// How a country compensates holidays that fall on a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubstituteRule {
+    /// Weekend holidays are simply lost.
+    None,
+    /// A holiday on Sunday is observed on the next free weekday.
+    SundayToMonday,
+}
+
+pub fn substitute_rule(country_code: &str) -> SubstituteRule {
+    match country_code {
+        // Public Holidays Act 36 of 1994, section 2(1)
+        "ZA" => SubstituteRule::SundayToMonday,
+        _ => SubstituteRule::None,
+    }
+}
+
+/// Fills in the `observed` date of every holiday that gets moved by the
+/// substitution rule of `country_code`.
+pub fn apply_observed_dates(country_code: &str, holidays: &mut [Holiday]) {
+    let rule = substitute_rule(country_code);
+    if rule == SubstituteRule::None {
+        return;
+    }
+
+    let mut taken: HashSet<NaiveDate> = holidays
+        .iter()
+        .filter(|h| h.kind().is_day_off())
+        .map(|h| h.date)
+        .collect();
+
+    let mut order: Vec<usize> = (0..holidays.len()).collect();
+    order.sort_by_key(|&i| holidays[i].date);
+
+    for i in order {
+        let holiday = &mut holidays[i];
+        if !holiday.kind().is_day_off() || holiday.date.weekday() != Weekday::Sun {
+            continue;
+        }
+
+        // The next day may already be a holiday (e.g. Christmas on a Sunday)
+        let mut observed = holiday.date.succ_opt();
+        while let Some(date) = observed {
+            if !taken.contains(&date) && !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+                break;
+            }
+            observed = date.succ_opt();
+        }
+
+        if let Some(date) = observed {
+            taken.insert(date);
+            holiday.observed = Some(date);
+        }
+    }
+}
@@ -1,35 +1,345 @@
+use crate::HolidayKind;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 use regex::Regex;
+use std::ops::RangeInclusive;
 
+/// Years a relative period may reach; further ones are surely typos, and
+/// would overflow the date arithmetic done on them.
+const SUPPORTED_YEARS: RangeInclusive<i32> = 1..=9999;
+
+/// Month names in English, French, Kreol and Afrikaans, without accents.
+const MONTH_NAMES: [&[&str]; 12] = [
+    &["january", "janvier", "zanvie", "januarie"],
+    &["february", "fevrier", "fevriye", "februarie"],
+    &["march", "mars", "maart"],
+    &["april", "avril"],
+    &["may", "mai", "me", "mei"],
+    &["june", "juin", "zin", "junie"],
+    &["july", "juillet", "zilye", "julie"],
+    &["august", "aout", "out", "augustus"],
+    &["september", "septembre", "septam"],
+    &["october", "octobre", "oktob", "oktober"],
+    &["november", "novembre", "novam"],
+    &["december", "decembre", "desam", "desember"],
+];
+
+/// Replaces accented lowercase letters with their plain counterparts.
+fn fold_accents(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'à' | 'â' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' => 'i',
+            'ô' | 'ö' => 'o',
+            'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            c => c,
+        })
+        .collect()
+}
+
+/// Parses a month number (`5`), or a month name or an abbreviation of at
+/// least three letters in English, French, Kreol or Afrikaans (`may`, `mai`,
+/// `me`, `mei`).
 pub fn parse_month(month_str: &str) -> Option<u32> {
-    // First, try to parse as a number
-    if let Ok(month_num) = month_str.parse::<u32>() {
-        if (1..=12).contains(&month_num) {
-            return Some(month_num);
+    let input = fold_accents(&month_str.trim().trim_end_matches('.').to_lowercase());
+
+    // `+2` is a relative month, left to `parse_period`
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        let month_num = input.parse::<u32>().ok()?;
+        return (1..=12).contains(&month_num).then_some(month_num);
+    }
+
+    // Full names of any length, or unambiguous prefixes of three letters or more
+    let matches = |exact: bool| -> Vec<u32> {
+        (1..=12)
+            .filter(|&m| {
+                MONTH_NAMES[m as usize - 1].iter().any(|name| {
+                    *name == input || (!exact && input.len() >= 3 && name.starts_with(&input))
+                })
+            })
+            .collect()
+    };
+    match (matches(true).as_slice(), matches(false).as_slice()) {
+        ([month], _) | ([], [month]) => Some(*month),
+        _ => None,
+    }
+}
+
+/// A span of time given on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Year(i32),
+    Month(i32, u32),
+    /// An ISO week, starting on Monday.
+    Week(NaiveDate),
+    Day(NaiveDate),
+}
+
+impl Period {
+    pub fn first_day(&self) -> NaiveDate {
+        match *self {
+            Period::Year(year) => NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+            Period::Month(year, month) => NaiveDate::from_ymd_opt(year, month, 1).unwrap(),
+            Period::Week(date) | Period::Day(date) => date,
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.first_day().year()
+    }
+
+    /// The month the period starts in, unless it spans a whole year.
+    pub fn month(&self) -> Option<u32> {
+        match self {
+            Period::Year(_) => None,
+            _ => Some(self.first_day().month()),
+        }
+    }
+
+    /// The period moved by `amount` of its own unit, as long as it stays
+    /// within `SUPPORTED_YEARS`.
+    fn shift(&self, amount: i64) -> Option<Period> {
+        let period = match *self {
+            Period::Year(year) => {
+                let year = i32::try_from(year as i64 + amount).ok()?;
+                NaiveDate::from_ymd_opt(year, 1, 1)?;
+                Some(Period::Year(year))
+            }
+            Period::Month(year, month) => {
+                let index = year as i64 * 12 + month as i64 - 1 + amount;
+                let year = i32::try_from(index.div_euclid(12)).ok()?;
+                NaiveDate::from_ymd_opt(year, index.rem_euclid(12) as u32 + 1, 1)?;
+                Some(Period::Month(year, index.rem_euclid(12) as u32 + 1))
+            }
+            Period::Week(date) => date
+                .checked_add_signed(Duration::try_weeks(amount)?)
+                .map(Period::Week),
+            Period::Day(date) => date
+                .checked_add_signed(Duration::try_days(amount)?)
+                .map(Period::Day),
+        }?;
+        SUPPORTED_YEARS.contains(&period.year()).then_some(period)
+    }
+}
+
+/// Parses a year, month, week or day, either absolute (`2026`, `2026-05`,
+/// `may 2026`, `2026-W18`, `2026-05-01`) or relative to `today` (`next year`,
+/// `this month`, `last week`, `tomorrow`, `+3m`, `-1y`, `+2w`, `+10d`). A bare
+/// `next`, `last` or `+2` counts in months.
+pub fn parse_period(period_str: &str, today: NaiveDate) -> Option<Period> {
+    let input = fold_accents(&period_str.trim().to_lowercase());
+    let this_week = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let current = |unit: &str| match unit {
+        "y" | "year" | "an" | "annee" | "lane" => Some(Period::Year(today.year())),
+        "m" | "month" | "mois" | "mwa" | "" => Some(Period::Month(today.year(), today.month())),
+        "w" | "week" | "semaine" | "semenn" => Some(Period::Week(this_week)),
+        "d" | "day" | "jour" | "zour" => Some(Period::Day(today)),
+        _ => None,
+    };
+
+    match input.as_str() {
+        "today" | "aujourd'hui" | "zordi" => return Some(Period::Day(today)),
+        "tomorrow" | "demain" | "demin" => return Period::Day(today).shift(1),
+        "yesterday" | "hier" | "yer" => return Period::Day(today).shift(-1),
+        _ => {}
+    }
+
+    // `next year`, `this month`, `last week`, or just `next`
+    let relative_regex = Regex::new(
+        r"^(this|current|next|last|previous|prev|ce|sa|prochain|prosenn|dernier|precedent|pase)(?:\s+([a-z]+))?$",
+    )
+    .unwrap();
+    if let Some(captures) = relative_regex.captures(&input) {
+        let offset = match &captures[1] {
+            "this" | "current" | "ce" | "sa" => 0,
+            "next" | "prochain" | "prosenn" => 1,
+            _ => -1,
+        };
+        let unit = captures.get(2).map_or("", |m| m.as_str());
+        return current(unit)?.shift(offset);
+    }
+
+    // `+3m`, `-1y`, `+2 weeks`, or just `+2`
+    let offset_regex = Regex::new(r"^([+-]\d{1,6})\s*([a-z]*)$").unwrap();
+    if let Some(captures) = offset_regex.captures(&input) {
+        let offset = captures[1].parse::<i64>().ok()?;
+        let unit = &captures[2];
+        let period = current(unit).or_else(|| current(unit.strip_suffix('s')?))?;
+        return period.shift(offset);
+    }
+
+    let iso_regex = Regex::new(r"^(\d{4})(?:-(\d{1,2})(?:-(\d{1,2}))?)?$").unwrap();
+    if let Some(captures) = iso_regex.captures(&input) {
+        let year = captures[1].parse::<i32>().ok()?;
+        let number = |i: usize| captures.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+        return match (number(2), number(3)) {
+            (None, _) => Some(Period::Year(year)),
+            (Some(month), None) => {
+                NaiveDate::from_ymd_opt(year, month, 1).map(|_| Period::Month(year, month))
+            }
+            (Some(month), Some(day)) => NaiveDate::from_ymd_opt(year, month, day).map(Period::Day),
+        };
+    }
+
+    let week_regex = Regex::new(r"^(\d{4})-?w(\d{1,2})$").unwrap();
+    if let Some(captures) = week_regex.captures(&input) {
+        let year = captures[1].parse::<i32>().ok()?;
+        let week = captures[2].parse::<u32>().ok()?;
+        return NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).map(Period::Week);
+    }
+
+    // The date format used in the output, e.g. `01-05-2026`
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%d-%m-%Y") {
+        return Some(Period::Day(date));
+    }
+
+    // `may 2026` or `2026 may`
+    let named_regex = Regex::new(r"^(?:([a-z.]+)\s+(\d{4})|(\d{4})\s+([a-z.]+))$").unwrap();
+    if let Some(captures) = named_regex.captures(&input) {
+        let name = captures.get(1).or(captures.get(4))?.as_str();
+        let year = captures
+            .get(2)
+            .or(captures.get(3))?
+            .as_str()
+            .parse::<i32>()
+            .ok()?;
+        return Some(Period::Month(year, parse_month(name)?));
+    }
+
+    None
+}
+
+pub fn parse_kind(kind_str: &str) -> Option<HolidayKind> {
+    match kind_str.trim().to_lowercase().as_str() {
+        "public" => Some(HolidayKind::Public),
+        "bank" => Some(HolidayKind::Bank),
+        "optional" | "religious" => Some(HolidayKind::Optional),
+        "observance" => Some(HolidayKind::Observance),
+        "school" => Some(HolidayKind::School),
+        _ => None,
+    }
+}
+
+/// Parses a single year (`2026`, `next year`) or an inclusive range of years
+/// (`2020..2030`, `2020..=2030` or `2020-2030`).
+pub fn parse_year_range(range_str: &str, today: NaiveDate) -> Option<RangeInclusive<i32>> {
+    let range_regex = Regex::new(r"^\s*(\d{4})\s*(?:\.\.=?|-)\s*(\d{4})\s*$").unwrap();
+    if let Some(captures) = range_regex.captures(range_str) {
+        let start = captures[1].parse::<i32>().ok()?;
+        let end = captures[2].parse::<i32>().ok()?;
+        return (start <= end).then_some(start..=end);
+    }
+
+    let year = parse_period(range_str, today)?.year();
+    Some(year..=year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A Monday, so that weeks start on it.
+    fn today() -> NaiveDate {
+        ymd(2026, 10, 19)
+    }
+
+    #[test]
+    fn month_numbers_and_names() {
+        assert_eq!(parse_month("5"), Some(5));
+        assert_eq!(parse_month("05"), Some(5));
+        assert_eq!(parse_month("may"), Some(5));
+        assert_eq!(parse_month("mei"), Some(5));
+        assert_eq!(parse_month("Sept."), Some(9));
+        assert_eq!(parse_month("zanvie"), Some(1));
+    }
+
+    #[test]
+    fn month_names_with_accents_in_any_case() {
+        assert_eq!(parse_month("août"), Some(8));
+        assert_eq!(parse_month("AOÛT"), Some(8));
+        assert_eq!(parse_month("FÉVRIER"), Some(2));
+        assert_eq!(parse_month("Décembre"), Some(12));
+        assert_eq!(
+            parse_period("FÉVRIER 2027", today()),
+            Some(Period::Month(2027, 2))
+        );
+    }
+
+    #[test]
+    fn invalid_months() {
+        for input in ["0", "13", "", "ma", "ju", "+2", "-1", "smarch"] {
+            assert_eq!(parse_month(input), None, "{}", input);
         }
     }
 
-    // If not a number, try to match against month names or abbreviations
-    let month_str_lower = month_str.to_lowercase();
-    let month_regex =
-        Regex::new(r"^(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*$").unwrap();
-
-    if let Some(captures) = month_regex.captures(&month_str_lower) {
-        match captures.get(1).unwrap().as_str() {
-            "jan" => Some(1),
-            "feb" => Some(2),
-            "mar" => Some(3),
-            "apr" => Some(4),
-            "may" => Some(5),
-            "jun" => Some(6),
-            "jul" => Some(7),
-            "aug" => Some(8),
-            "sep" => Some(9),
-            "oct" => Some(10),
-            "nov" => Some(11),
-            "dec" => Some(12),
-            _ => None,
+    #[test]
+    fn relative_months() {
+        let month = |input| parse_period(input, today());
+        assert_eq!(month("+1"), Some(Period::Month(2026, 11)));
+        assert_eq!(month("+2"), Some(Period::Month(2026, 12)));
+        assert_eq!(month("+12"), Some(Period::Month(2027, 10)));
+        assert_eq!(month("-10"), Some(Period::Month(2025, 12)));
+        assert_eq!(month("next"), Some(Period::Month(2026, 11)));
+        assert_eq!(month("last"), Some(Period::Month(2026, 9)));
+        assert_eq!(month("this month"), Some(Period::Month(2026, 10)));
+        assert_eq!(month("+3m"), Some(Period::Month(2027, 1)));
+    }
+
+    #[test]
+    fn relative_years_weeks_and_days() {
+        let period = |input| parse_period(input, today());
+        assert_eq!(period("next year"), Some(Period::Year(2027)));
+        assert_eq!(period("-1y"), Some(Period::Year(2025)));
+        assert_eq!(period("+2 years"), Some(Period::Year(2028)));
+        assert_eq!(period("last week"), Some(Period::Week(ymd(2026, 10, 12))));
+        assert_eq!(period("+10d"), Some(Period::Day(ymd(2026, 10, 29))));
+        assert_eq!(period("tomorrow"), Some(Period::Day(ymd(2026, 10, 20))));
+        assert_eq!(period("demain"), Some(Period::Day(ymd(2026, 10, 20))));
+    }
+
+    #[test]
+    fn absolute_periods() {
+        let period = |input| parse_period(input, today());
+        assert_eq!(period("2026"), Some(Period::Year(2026)));
+        assert_eq!(period("2026-05"), Some(Period::Month(2026, 5)));
+        assert_eq!(period("may 2026"), Some(Period::Month(2026, 5)));
+        assert_eq!(period("2026 mai"), Some(Period::Month(2026, 5)));
+        assert_eq!(period("2026-W18"), Some(Period::Week(ymd(2026, 4, 27))));
+        assert_eq!(period("2026-05-01"), Some(Period::Day(ymd(2026, 5, 1))));
+        assert_eq!(period("01-05-2026"), Some(Period::Day(ymd(2026, 5, 1))));
+    }
+
+    #[test]
+    fn invalid_periods() {
+        for input in [
+            "",
+            "soon",
+            "2026-13",
+            "2026-02-30",
+            "2026-W54",
+            "next fortnight",
+            "+2x",
+            "+999999y",
+            "-999999m",
+        ] {
+            assert_eq!(parse_period(input, today()), None, "{}", input);
         }
-    } else {
-        None
+    }
+
+    #[test]
+    fn year_ranges() {
+        let range = |input| parse_year_range(input, today());
+        assert_eq!(range("2020..2030"), Some(2020..=2030));
+        assert_eq!(range("2020..=2030"), Some(2020..=2030));
+        assert_eq!(range("2020-2030"), Some(2020..=2030));
+        assert_eq!(range("2026"), Some(2026..=2026));
+        assert_eq!(range("next year"), Some(2027..=2027));
+        assert_eq!(range("2030..2020"), None);
+        assert_eq!(range("2020..later"), None);
     }
 }
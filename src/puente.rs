@@ -1,62 +1,143 @@
+use crate::i18n::{month_name, tr, weekday_name};
+use crate::leave::Leave;
+use crate::table::apply_style;
 use crate::Holiday;
-use chrono::{Datelike, Local, Month, NaiveDate, Weekday};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use colored::Colorize;
-use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets, Cell, Color, ContentArrangement, Table};
-use num_traits::FromPrimitive;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
 use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
 #[derive(Debug, Hash, Eq, PartialEq)]
-struct PuenteDay {
-    date: NaiveDate,
-    related_holidays: Vec<NaiveDate>,
+pub struct PuenteDay {
+    pub date: NaiveDate,
+    pub related_holidays: Vec<NaiveDate>,
+}
+
+static WEEKEND: OnceLock<Vec<Weekday>> = OnceLock::new();
+
+/// Sets the days off of every week, Saturday and Sunday by default. Only the
+/// first call has an effect.
+pub fn set_weekend(days: Vec<Weekday>) {
+    let _ = WEEKEND.set(days);
 }
 
-fn is_weekday(date: NaiveDate) -> bool {
+pub fn is_weekday(date: NaiveDate) -> bool {
     let weekday = date.weekday();
-    weekday != Weekday::Sat && weekday != Weekday::Sun
+    match WEEKEND.get() {
+        Some(weekend) => !weekend.contains(&weekday),
+        None => weekday != Weekday::Sat && weekday != Weekday::Sun,
+    }
 }
 
-pub fn print_puente_days(month: Option<u32>, year: i32, holidays: &[&Holiday], country_code: &str) {
+pub fn print_puente_days(
+    month: Option<u32>,
+    year: i32,
+    holidays: &[&Holiday],
+    leave: &[Leave],
+    country_code: &str,
+) {
     let current_date = Local::now().date_naive();
     let mut table = Table::new();
     table
         .set_header(vec![
-            Cell::new("Holiday\nDates").fg(Color::Cyan),
-            Cell::new("Holiday\nDays").fg(Color::Cyan),
-            Cell::new("Holiday\nNames").fg(Color::Cyan),
-            Cell::new("Bridge\nDates").fg(Color::Green),
-            Cell::new("Bridge\nDays").fg(Color::Green),
+            Cell::new(tr!("bridge.header.holiday_dates")).fg(Color::Cyan),
+            Cell::new(tr!("bridge.header.holiday_days")).fg(Color::Cyan),
+            Cell::new(tr!("bridge.header.holiday_names")).fg(Color::Cyan),
+            Cell::new(tr!("bridge.header.bridge_dates")).fg(Color::Green),
+            Cell::new(tr!("bridge.header.bridge_days")).fg(Color::Green),
         ])
-        .load_preset(presets::UTF8_FULL)
-        .apply_modifier(UTF8_ROUND_CORNERS)
         .set_content_arrangement(ContentArrangement::Dynamic)
         .set_width(140);
+    apply_style(&mut table);
 
     match month {
         Some(m) => println!(
             "\n{}",
-            format!(
-                "🌉 Bridge days for {} {} ({}):",
-                Month::from_u32(m).unwrap().name(),
-                year,
-                country_code
-            )
-            .bold()
-            .yellow()
+            tr!("bridge.title_month", month_name(m), year, country_code)
+                .bold()
+                .yellow()
         ),
         None => println!(
             "\n{}",
-            format!("🌉 Bridge days for the year {} ({}):", year, country_code)
-                .bold()
-                .yellow()
+            tr!("bridge.title_year", year, country_code).bold().yellow()
         ),
     }
 
+    // Booked leave is already a day off, so bridges can extend it too
+    let holiday_count = holidays.len();
+    let leave_holidays: Vec<Holiday> = leave.iter().map(Leave::as_holiday).collect();
+    let holidays: Vec<&Holiday> = holidays
+        .iter()
+        .copied()
+        .chain(leave_holidays.iter())
+        .collect();
+    let holidays = holidays.as_slice();
+
+    let puente_days = find_puente_days(holidays);
+
+    // Filter by month if specified
+    let filtered_puente_days: Vec<&PuenteDay> = if let Some(m) = month {
+        puente_days.iter().filter(|p| p.date.month() == m).collect()
+    } else {
+        puente_days.iter().collect()
+    };
+
+    // Add rows to table
+    for puente_day in &filtered_puente_days {
+        let mut related_holidays = puente_day.related_holidays.clone();
+        related_holidays.sort(); // Sort related holidays by date
+        add_row_to_table(
+            &mut table,
+            &related_holidays,
+            holidays,
+            puente_day.date,
+            current_date,
+        );
+    }
+
+    if !filtered_puente_days.is_empty() {
+        println!("{table}");
+        println!(
+            "\n{}",
+            tr!("bridge.found", filtered_puente_days.len())
+                .bold()
+                .green()
+        );
+    } else {
+        println!("\n{}", tr!("bridge.none").bold().red());
+    }
+
+    let total_holidays_message = match month {
+        Some(m) => tr!(
+            "bridge.total_month",
+            holidays[..holiday_count]
+                .iter()
+                .filter(|&h| h.observed_date().month() == m)
+                .count()
+        ),
+        None => tr!("bridge.total_year", holiday_count),
+    };
+
+    println!("{}", total_holidays_message.bold().blue());
+    println!();
+}
+
+/// Finds the working days that bridge holidays to a weekend or to other
+/// holidays, in chronological order.
+/// Only guaranteed days off are bridged.
+pub fn find_puente_days(holidays: &[&Holiday]) -> Vec<PuenteDay> {
+    let holidays: Vec<&Holiday> = holidays
+        .iter()
+        .copied()
+        .filter(|h| h.kind().is_day_off())
+        .collect();
+    let holidays = holidays.as_slice();
     let mut unique_puentes = HashSet::new();
     let mut puente_days = Vec::new();
 
     // Collect all holiday dates for easier comparison
-    let holiday_dates: HashSet<NaiveDate> = holidays.iter().map(|h| h.date).collect();
+    let holiday_dates: HashSet<NaiveDate> = holidays.iter().map(|h| h.observed_date()).collect();
 
     // Create a map of consecutive holidays (only weekdays)
     let mut consecutive_holidays: HashMap<NaiveDate, Vec<NaiveDate>> = HashMap::new();
@@ -71,17 +152,14 @@ pub fn print_puente_days(month: Option<u32>, year: i32, holidays: &[&Holiday], c
     for window in sorted_holidays.windows(2) {
         if let [date1, date2] = window {
             if date2.signed_duration_since(*date1).num_days() == 1 {
-                consecutive_holidays
-                    .entry(*date1)
-                    .or_insert_with(Vec::new)
-                    .push(*date2);
+                consecutive_holidays.entry(*date1).or_default().push(*date2);
             }
         }
     }
 
     // Process regular puente scenarios
     for holiday in holidays {
-        let holiday_date = holiday.date;
+        let holiday_date = holiday.observed_date();
         if !is_weekday(holiday_date) {
             continue; // Skip weekend holidays for regular puente scenarios
         }
@@ -153,19 +231,19 @@ pub fn print_puente_days(month: Option<u32>, year: i32, holidays: &[&Holiday], c
 
     // Case 5: Sandwich days between holidays (only considering weekdays)
     for holiday1 in holidays {
-        if !is_weekday(holiday1.date) {
+        if !is_weekday(holiday1.observed_date()) {
             continue;
         }
         for holiday2 in holidays {
-            if !is_weekday(holiday2.date) {
+            if !is_weekday(holiday2.observed_date()) {
                 continue;
             }
-            if holiday1.date < holiday2.date {
-                if let Some(middle_date) = holiday1.date.succ_opt() {
-                    if middle_date < holiday2.date
+            if holiday1.observed_date() < holiday2.observed_date() {
+                if let Some(middle_date) = holiday1.observed_date().succ_opt() {
+                    if middle_date < holiday2.observed_date()
                         && (holiday2
-                            .date
-                            .signed_duration_since(holiday1.date)
+                            .observed_date()
+                            .signed_duration_since(holiday1.observed_date())
                             .num_days()
                             == 2)
                         && !holiday_dates.contains(&middle_date)
@@ -175,7 +253,7 @@ pub fn print_puente_days(month: Option<u32>, year: i32, holidays: &[&Holiday], c
                             &mut unique_puentes,
                             &mut puente_days,
                             middle_date,
-                            vec![holiday1.date, holiday2.date],
+                            vec![holiday1.observed_date(), holiday2.observed_date()],
                         );
                     }
                 }
@@ -217,56 +295,8 @@ pub fn print_puente_days(month: Option<u32>, year: i32, holidays: &[&Holiday], c
     }
 
     // Sort puente_days by complete date
-    puente_days.sort_by(|a, b| b.date.cmp(&a.date));
-
-    // Filter by month if specified
-    let filtered_puente_days: Vec<&PuenteDay> = if let Some(m) = month {
-        puente_days.iter().filter(|p| p.date.month() == m).collect()
-    } else {
-        puente_days.iter().collect()
-    };
-
-    // Reverse the filtered days to show them in chronological order
-    let filtered_puente_days: Vec<_> = filtered_puente_days.into_iter().rev().collect();
-
-    // Add rows to table
-    for puente_day in &filtered_puente_days {
-        let mut related_holidays = puente_day.related_holidays.clone();
-        related_holidays.sort(); // Sort related holidays by date
-        add_row_to_table(
-            &mut table,
-            &related_holidays,
-            holidays,
-            puente_day.date,
-            current_date,
-        );
-    }
-
-    if !filtered_puente_days.is_empty() {
-        println!("{table}");
-        println!(
-            "\n{}",
-            format!(
-                "🎯 Found {} bridge opportunities!",
-                filtered_puente_days.len()
-            )
-            .bold()
-            .green()
-        );
-    } else {
-        println!("\n{}", "😢 No bridge days found.".bold().red());
-    }
-
-    let total_holidays_message = match month {
-        Some(m) => format!(
-            "📅 Total holidays for this month: {}",
-            holidays.iter().filter(|&h| h.date.month() == m).count()
-        ),
-        None => format!("📅 Total holidays for this year: {}", holidays.len()),
-    };
-
-    println!("{}", total_holidays_message.bold().blue());
-    println!();
+    puente_days.sort_by_key(|p| p.date);
+    puente_days
 }
 
 fn add_puente(
@@ -305,7 +335,7 @@ fn add_row_to_table(
 
     let holiday_days_str: String = holiday_dates
         .iter()
-        .map(|d| weekday_to_string(d.weekday()))
+        .map(|d| weekday_name(d.weekday()))
         .collect::<Vec<_>>()
         .join("\n");
 
@@ -326,26 +356,13 @@ fn add_row_to_table(
         Cell::new(holiday_days_str).fg(holiday_color),
         Cell::new(holiday_names_str).fg(holiday_color),
         Cell::new(puente_date.format("%d-%m-%Y").to_string()).fg(color),
-        Cell::new(weekday_to_string(puente_date.weekday())).fg(color),
+        Cell::new(weekday_name(puente_date.weekday())).fg(color),
     ]);
 }
 
 fn get_holiday_name(holidays: &[&Holiday], date: NaiveDate) -> Option<String> {
     holidays
         .iter()
-        .find(|h| h.date == date)
+        .find(|h| h.observed_date() == date)
         .map(|h| h.name.clone())
 }
-
-fn weekday_to_string(weekday: Weekday) -> String {
-    match weekday {
-        Weekday::Mon => "Monday",
-        Weekday::Tue => "Tuesday",
-        Weekday::Wed => "Wednesday",
-        Weekday::Thu => "Thursday",
-        Weekday::Fri => "Friday",
-        Weekday::Sat => "Saturday",
-        Weekday::Sun => "Sunday",
-    }
-    .to_string()
-}
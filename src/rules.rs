@@ -0,0 +1,181 @@
+use crate::lunar;
+use crate::{Holiday, HolidayKind};
+use chrono::{Duration, NaiveDate};
+
+/// How the date of a holiday is derived for a given year.
+#[derive(Debug, Clone, Copy)]
+pub enum DateRule {
+    /// Same day and month every year.
+    Fixed { month: u32, day: u32 },
+    /// A number of days relative to Western Easter Sunday.
+    Easter { offset: i64 },
+    /// Computed from a lunar or lunisolar calendar.
+    Lunar(fn(i32) -> Option<NaiveDate>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HolidayRule {
+    pub name: &'static str,
+    pub kind: HolidayKind,
+    pub rule: DateRule,
+    /// The official date is only known once announced, e.g. after moon sighting.
+    pub tentative: bool,
+}
+
+const fn fixed(name: &'static str, month: u32, day: u32) -> HolidayRule {
+    HolidayRule {
+        name,
+        kind: HolidayKind::Public,
+        rule: DateRule::Fixed { month, day },
+        tentative: false,
+    }
+}
+
+const fn easter(name: &'static str, offset: i64) -> HolidayRule {
+    HolidayRule {
+        name,
+        kind: HolidayKind::Public,
+        rule: DateRule::Easter { offset },
+        tentative: false,
+    }
+}
+
+const fn lunar(name: &'static str, date: fn(i32) -> Option<NaiveDate>) -> HolidayRule {
+    HolidayRule {
+        name,
+        kind: HolidayKind::Public,
+        rule: DateRule::Lunar(date),
+        tentative: true,
+    }
+}
+
+const MU_RULES: &[HolidayRule] = &[
+    fixed("New Year's Day", 1, 1),
+    fixed("New Year", 1, 2),
+    fixed("Abolition of Slavery", 2, 1),
+    lunar("Chinese Spring Festival", lunar::spring_festival),
+    lunar("Maha Shivaratree", lunar::maha_shivaratree),
+    fixed("Independence and Republic Day", 3, 12),
+    lunar("Ougadi", lunar::ougadi),
+    lunar("Eid-Ul-Fitr", lunar::eid_ul_fitr),
+    fixed("Labour Day", 5, 1),
+    fixed("Assumption of the Blessed Virgin Mary", 8, 15),
+    lunar("Ganesh Chaturthi", lunar::ganesh_chaturthi),
+    lunar("Divali", lunar::diwali),
+    fixed("All Saints Day", 11, 1),
+    fixed("Arrival of Indentured Labourers", 11, 2),
+    fixed("Christmas Day", 12, 25),
+];
+
+const ZA_RULES: &[HolidayRule] = &[
+    fixed("New Year's Day", 1, 1),
+    fixed("Human Rights Day", 3, 21),
+    easter("Good Friday", -2),
+    easter("Family Day", 1),
+    fixed("Freedom Day", 4, 27),
+    fixed("Workers' Day", 5, 1),
+    fixed("Youth Day", 6, 16),
+    fixed("National Women's Day", 8, 9),
+    fixed("Heritage Day", 9, 24),
+    fixed("Day of Reconciliation", 12, 16),
+    fixed("Christmas Day", 12, 25),
+    fixed("Day of Goodwill", 12, 26),
+];
+
+const FR_RULES: &[HolidayRule] = &[
+    fixed("New Year's Day", 1, 1),
+    easter("Easter Monday", 1),
+    fixed("Labour Day", 5, 1),
+    fixed("Victory in Europe Day", 5, 8),
+    easter("Ascension Day", 39),
+    easter("Whit Monday", 50),
+    fixed("Bastille Day", 7, 14),
+    fixed("Assumption of Mary", 8, 15),
+    fixed("All Saints' Day", 11, 1),
+    fixed("Armistice Day", 11, 11),
+    fixed("Christmas Day", 12, 25),
+];
+
+pub fn country_rules(country_code: &str) -> Option<&'static [HolidayRule]> {
+    match country_code {
+        "MU" => Some(MU_RULES),
+        "ZA" => Some(ZA_RULES),
+        "FR" => Some(FR_RULES),
+        _ => None,
+    }
+}
+
+/// Computes the holidays of `year` from the built-in rules of `country_code`.
+/// Every returned holiday is marked as projected.
+pub fn project_holidays(country_code: &str, year: i32) -> Option<Vec<Holiday>> {
+    let rules = country_rules(country_code)?;
+    let mut holidays: Vec<Holiday> = rules
+        .iter()
+        .filter_map(|rule| {
+            Some(Holiday {
+                name: rule.name.to_string(),
+                date: rule_date(rule.rule, year)?,
+                kind: Some(rule.kind),
+                observed: None,
+                projected: true,
+                tentative: rule.tentative,
+            })
+        })
+        .collect();
+    holidays.sort_by_key(|h| h.date);
+    Some(holidays)
+}
+
+fn rule_date(rule: DateRule, year: i32) -> Option<NaiveDate> {
+    match rule {
+        DateRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+        DateRule::Easter { offset } => {
+            easter_sunday(year).and_then(|d| d.checked_add_signed(Duration::days(offset)))
+        }
+        DateRule::Lunar(date) => date(year),
+    }
+}
+
+/// Western Easter Sunday using the anonymous Gregorian algorithm.
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easter_sunday_dates() {
+        for (year, month, day) in [
+            (2000, 4, 23),
+            (2019, 4, 21),
+            (2023, 4, 9),
+            (2024, 3, 31),
+            (2025, 4, 20),
+            (2026, 4, 5),
+            (2038, 4, 25),
+        ] {
+            assert_eq!(
+                easter_sunday(year),
+                NaiveDate::from_ymd_opt(year, month, day),
+                "{}",
+                year
+            );
+        }
+    }
+}
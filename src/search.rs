@@ -0,0 +1,47 @@
+use crate::i18n::{tr, weekday_name};
+use crate::table::new_table;
+use crate::Holiday;
+use chrono::Datelike;
+use colored::Colorize;
+use comfy_table::{Cell, Color};
+use regex::{Regex, RegexBuilder};
+
+/// Builds a case-insensitive matcher for holiday names.
+pub fn name_matcher(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
+pub fn search_holidays<'a>(matcher: &Regex, holidays: &'a [Holiday]) -> Vec<&'a Holiday> {
+    holidays
+        .iter()
+        .filter(|holiday| matcher.is_match(&holiday.name))
+        .collect()
+}
+
+pub fn print_search_results(pattern: &str, results: &[(String, Holiday)]) {
+    println!("\n{}", tr!("search.title", pattern).bold().yellow());
+
+    if results.is_empty() {
+        println!("\n{}", tr!("search.none").bold().red());
+        println!();
+        return;
+    }
+
+    let mut table = new_table(vec![
+        tr!("header.country"),
+        tr!("header.date"),
+        tr!("header.day"),
+        tr!("header.holiday"),
+    ]);
+    for (country, holiday) in results {
+        table.add_row(vec![
+            Cell::new(country).fg(Color::Yellow),
+            Cell::new(holiday.date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+            Cell::new(weekday_name(holiday.date.weekday())).fg(Color::Cyan),
+            Cell::new(holiday.display_name()).fg(Color::Green),
+        ]);
+    }
+    println!("{table}");
+    println!("\n{}", tr!("search.total", results.len()).bold().blue());
+    println!();
+}
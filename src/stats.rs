@@ -0,0 +1,166 @@
+use crate::changes::{bridge_count, weekday_holiday_count};
+use crate::i18n::{tr, weekday_short};
+use crate::long_weekends::find_long_weekends;
+use crate::puente::is_weekday;
+use crate::table::new_table;
+use crate::Holiday;
+use chrono::{Datelike, Weekday};
+use colored::Colorize;
+use comfy_table::{Cell, Color};
+
+const BAR_WIDTH: usize = 30;
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+#[derive(Debug)]
+pub struct YearStats {
+    pub year: i32,
+    pub holidays: usize,
+    /// Holidays falling on a weekend, which give no extra day off unless
+    /// they are observed on a weekday.
+    pub on_weekends: usize,
+    pub weekday_holidays: usize,
+    pub long_weekends: usize,
+    /// Length in days of the longest break that needs no leave.
+    pub longest_break: i64,
+    pub bridges: usize,
+    pub projected: bool,
+    pub weekdays: [usize; 7],
+}
+
+pub fn year_stats(year: i32, holidays: &[Holiday]) -> YearStats {
+    let mut weekdays = [0; 7];
+    for holiday in holidays.iter().filter(|h| h.kind().is_day_off()) {
+        weekdays[holiday.observed_date().weekday().num_days_from_monday() as usize] += 1;
+    }
+
+    let long_weekends = find_long_weekends(holidays, year);
+
+    YearStats {
+        year,
+        holidays: holidays.len(),
+        on_weekends: holidays
+            .iter()
+            .filter(|h| h.kind().is_day_off() && !is_weekday(h.observed_date()))
+            .count(),
+        weekday_holidays: weekday_holiday_count(holidays),
+        long_weekends: long_weekends.len(),
+        longest_break: long_weekends.iter().map(|l| l.length()).max().unwrap_or(0),
+        bridges: bridge_count(holidays),
+        projected: holidays.iter().any(|h| h.projected),
+        weekdays,
+    }
+}
+
+fn bar(value: usize, max: usize) -> String {
+    let width = (value * BAR_WIDTH).checked_div(max).unwrap_or(0);
+    "#".repeat(width)
+}
+
+pub fn print_stats(stats: &[YearStats], country_code: &str) {
+    let (Some(first), Some(last)) = (stats.first(), stats.last()) else {
+        return;
+    };
+
+    println!(
+        "\n{}",
+        tr!("stats.title", country_code, first.year, last.year)
+            .bold()
+            .yellow()
+    );
+
+    let mut table = new_table(vec![
+        tr!("header.year"),
+        tr!("header.holidays"),
+        tr!("header.on_weekends"),
+        tr!("header.weekday_holidays"),
+        tr!("header.long_weekends"),
+        tr!("header.longest_break"),
+        tr!("header.bridges"),
+    ]);
+    for year in stats {
+        let label = if year.projected {
+            format!("{}*", year.year)
+        } else {
+            year.year.to_string()
+        };
+        table.add_row(vec![
+            Cell::new(label).fg(Color::Cyan),
+            Cell::new(year.holidays),
+            Cell::new(year.on_weekends).fg(Color::Red),
+            Cell::new(year.weekday_holidays).fg(Color::Green),
+            Cell::new(year.long_weekends),
+            Cell::new(tr!("stats.days", year.longest_break)),
+            Cell::new(year.bridges),
+        ]);
+    }
+    println!("{table}");
+    if stats.iter().any(|s| s.projected) {
+        println!("{}", tr!("stats.projected").dimmed());
+    }
+
+    println!("\n{}", tr!("stats.per_year").bold().blue());
+    let max = stats.iter().map(|s| s.weekday_holidays).max().unwrap_or(0);
+    for year in stats {
+        println!(
+            "{} {:<width$} {}",
+            year.year,
+            bar(year.weekday_holidays, max),
+            year.weekday_holidays,
+            width = BAR_WIDTH
+        );
+    }
+
+    println!("\n{}", tr!("stats.per_weekday").bold().blue());
+    let mut totals = [0; 7];
+    for year in stats {
+        for (total, count) in totals.iter_mut().zip(year.weekdays) {
+            *total += count;
+        }
+    }
+    let max = totals.iter().copied().max().unwrap_or(0);
+    for (weekday, total) in WEEKDAYS.iter().zip(totals) {
+        println!(
+            "{:<4} {:<width$} {}",
+            weekday_short(*weekday),
+            bar(total, max),
+            total,
+            width = BAR_WIDTH
+        );
+    }
+
+    // A year is better when more holidays land on working days
+    let score = |s: &&YearStats| (s.weekday_holidays, s.long_weekends, s.bridges);
+    let best = stats.iter().max_by_key(score).unwrap();
+    let worst = stats.iter().min_by_key(score).unwrap();
+    println!(
+        "\n{}",
+        tr!(
+            "stats.best",
+            best.year,
+            best.weekday_holidays,
+            best.long_weekends
+        )
+        .bold()
+        .green()
+    );
+    println!(
+        "{}",
+        tr!(
+            "stats.worst",
+            worst.year,
+            worst.weekday_holidays,
+            worst.long_weekends
+        )
+        .bold()
+        .red()
+    );
+    println!();
+}
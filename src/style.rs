@@ -0,0 +1,94 @@
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+static COLOR: OnceLock<bool> = OnceLock::new();
+static ASCII: OnceLock<bool> = OnceLock::new();
+
+/// When to color the output, as given with `--color`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Only when printing to a terminal, unless `NO_COLOR` or
+    /// `CLICOLOR_FORCE` say otherwise.
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn from_name(name: &str) -> Option<ColorChoice> {
+        match name {
+            "auto" => Some(ColorChoice::Auto),
+            "always" => Some(ColorChoice::Always),
+            "never" => Some(ColorChoice::Never),
+            _ => None,
+        }
+    }
+
+    fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if env_set("NO_COLOR") {
+                    false
+                } else if env_set("CLICOLOR_FORCE") {
+                    std::env::var("CLICOLOR_FORCE").is_ok_and(|value| value != "0")
+                } else {
+                    std::io::stdout().is_terminal()
+                }
+            }
+        }
+    }
+}
+
+fn env_set(name: &str) -> bool {
+    std::env::var_os(name).is_some_and(|value| !value.is_empty())
+}
+
+/// Sets how everything is printed. Only the first call has an effect.
+pub fn init(color: ColorChoice, ascii: bool) {
+    let color = *COLOR.get_or_init(|| color.enabled());
+    let _ = ASCII.set(ascii);
+    colored::control::set_override(color);
+}
+
+/// Whether the output is colored.
+pub fn color() -> bool {
+    COLOR.get().copied().unwrap_or(false)
+}
+
+/// Whether to print plain ASCII, without emojis or box-drawing characters.
+pub fn ascii() -> bool {
+    ASCII.get().copied().unwrap_or(false)
+}
+
+/// `text` with its leading emoji dropped, or replaced by an ASCII symbol
+/// when there is one, and its other non-ASCII punctuation swapped.
+pub fn plain(text: &str) -> String {
+    let rest = text.trim_start_matches(|c: char| is_emoji(c) || c == '\u{FE0F}');
+    let symbol = match text.chars().next() {
+        Some('➕') => "+ ",
+        Some('➖') => "- ",
+        Some('⚠') => "! ",
+        _ => "",
+    };
+    let rest = if rest.len() == text.len() {
+        rest
+    } else {
+        rest.trim_start()
+    };
+
+    let mut result = String::from(symbol);
+    for c in rest.chars() {
+        match c {
+            '→' => result.push_str("->"),
+            '’' => result.push('\''),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x27BF)
+}
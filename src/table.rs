@@ -0,0 +1,34 @@
+use comfy_table::{modifiers, presets, Cell, Color, ContentArrangement, Table};
+
+/// A table in the style of `list`, with the given blue headers.
+pub fn new_table<T: ToString>(headers: Vec<T>) -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(
+            headers
+                .into_iter()
+                .map(|h| Cell::new(h).fg(Color::Blue))
+                .collect::<Vec<_>>(),
+        )
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(100);
+    apply_style(&mut table);
+    table
+}
+
+/// Draws `table` with rounded UTF-8 borders, or ASCII ones with `--ascii`,
+/// and colors its cells only when the output is colored.
+pub fn apply_style(table: &mut Table) -> &mut Table {
+    if crate::style::ascii() {
+        table.load_preset(presets::ASCII_FULL);
+    } else {
+        table
+            .load_preset(presets::UTF8_FULL)
+            .apply_modifier(modifiers::UTF8_ROUND_CORNERS);
+    }
+    table.force_no_tty();
+    if crate::style::color() {
+        table.enforce_styling();
+    }
+    table
+}
@@ -0,0 +1,175 @@
+use crate::cal::print_calendar_comparison;
+use crate::config::{deserialize_uppercase, serialize_uppercase};
+use crate::i18n::{tr, weekday_name};
+use crate::table::apply_style;
+use crate::{CountryHolidays, Holiday};
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate, Weekday};
+use colored::Colorize;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TeamMember {
+    #[serde(
+        deserialize_with = "deserialize_uppercase",
+        serialize_with = "serialize_uppercase"
+    )]
+    pub country: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(default = "default_work_week")]
+    pub work_week: Vec<Weekday>,
+}
+
+fn default_work_week() -> Vec<Weekday> {
+    vec![
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+    ]
+}
+
+impl TeamMember {
+    fn location(&self) -> String {
+        match &self.region {
+            Some(region) => format!("{}, {}", self.country, region),
+            None => self.country.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Team {
+    #[serde(default)]
+    pub members: BTreeMap<String, TeamMember>,
+}
+
+impl Team {
+    pub fn load(team_path: &Path) -> Result<Self> {
+        let team = std::fs::read_to_string(team_path)
+            .with_context(|| tr!("team.read_failed", format!("{:?}", team_path)))?;
+        let team: Team = toml::from_str(&team).with_context(|| tr!("team.parse_failed"))?;
+        Ok(team)
+    }
+
+    /// The distinct countries of the team members, in alphabetical order.
+    pub fn countries(&self) -> BTreeSet<String> {
+        self.members.values().map(|m| m.country.clone()).collect()
+    }
+
+    /// Team members who have a day off on `date` because of a holiday falling
+    /// on one of their working days.
+    fn members_off<'a>(
+        &'a self,
+        date: NaiveDate,
+        country_holidays: &'a [CountryHolidays],
+    ) -> Vec<(&'a str, &'a TeamMember, &'a Holiday)> {
+        self.members
+            .iter()
+            .filter(|(_, member)| member.work_week.contains(&date.weekday()))
+            .filter_map(|(name, member)| {
+                country_holidays
+                    .iter()
+                    .find(|c| c.country == member.country)?
+                    .holidays
+                    .iter()
+                    .find(|h| h.observed_date() == date && h.kind().is_day_off())
+                    .map(|holiday| (name.as_str(), member, holiday))
+            })
+            .collect()
+    }
+}
+
+pub fn print_team_calendar(
+    month: u32,
+    year: i32,
+    team: &Team,
+    country_holidays: &[CountryHolidays],
+) {
+    print_calendar_comparison(month, year, country_holidays, &[]);
+
+    let dates = NaiveDate::from_ymd_opt(year, month, 1)
+        .unwrap()
+        .iter_days()
+        .take_while(|d| d.month() == month);
+    print_members_off(team, country_holidays, dates, None);
+}
+
+pub fn print_team_next(
+    from: NaiveDate,
+    count: usize,
+    team: &Team,
+    country_holidays: &[CountryHolidays],
+) {
+    print_members_off(team, country_holidays, from.iter_days(), Some(count));
+}
+
+fn print_members_off(
+    team: &Team,
+    country_holidays: &[CountryHolidays],
+    dates: impl Iterator<Item = NaiveDate>,
+    limit: Option<usize>,
+) {
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new(tr!("header.date")).fg(Color::Blue),
+            Cell::new(tr!("header.day")).fg(Color::Blue),
+            Cell::new(tr!("header.off")).fg(Color::Blue),
+            Cell::new(tr!("header.holiday")).fg(Color::Blue),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(120);
+    apply_style(&mut table);
+
+    // Holidays are only known for the fetched years, so stop past them
+    let last_date = country_holidays
+        .iter()
+        .flat_map(|c| c.holidays.iter().map(|h| h.observed_date()))
+        .max();
+
+    let mut days_found = 0;
+    for date in dates {
+        if limit.is_some_and(|l| days_found >= l) || last_date.map_or(true, |d| date > d) {
+            break;
+        }
+
+        let off = team.members_off(date, country_holidays);
+        if off.is_empty() {
+            continue;
+        }
+        days_found += 1;
+
+        let members = off
+            .iter()
+            .map(|(name, member, _)| format!("{} ({})", name, member.location()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let holidays = off
+            .iter()
+            .map(|(_, member, holiday)| format!("{}: {}", member.country, holiday.display_name()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        table.add_row(vec![
+            Cell::new(date.format("%d-%m-%Y").to_string()).fg(Color::Cyan),
+            Cell::new(weekday_name(date.weekday())).fg(Color::Cyan),
+            Cell::new(members).fg(Color::Yellow),
+            Cell::new(holidays).fg(Color::Green),
+        ]);
+    }
+
+    println!("\n{}", tr!("team.title").bold().yellow());
+    if days_found == 0 {
+        println!("{}", tr!("team.everyone_working").bold().green());
+    } else {
+        println!("{table}");
+    }
+}
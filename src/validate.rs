@@ -0,0 +1,258 @@
+use crate::i18n::tr;
+use crate::HolidayKind;
+use chrono::{Datelike, NaiveDate};
+use colored::Colorize;
+use serde_json::error::Category;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// Years outside this range are almost certainly typos.
+const PLAUSIBLE_YEARS: RangeInclusive<i32> = 1900..=2200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The data is wrong and will be shown wrongly, or not at all.
+    Error,
+    /// The data is suspicious but may be intended.
+    Warning,
+}
+
+/// A problem found in a dataset, with where it was found, e.g.
+/// `years["2026"][3]`.
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub severity: Severity,
+    pub location: String,
+    pub message: String,
+}
+
+impl Issue {
+    fn error(location: String, message: String) -> Self {
+        Issue {
+            severity: Severity::Error,
+            location,
+            message,
+        }
+    }
+
+    fn warning(location: String, message: String) -> Self {
+        Issue {
+            severity: Severity::Warning,
+            location,
+            message,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let label = match self.severity {
+            Severity::Error => tr!("validate.error").red().bold(),
+            Severity::Warning => tr!("validate.warning").yellow().bold(),
+        };
+        write!(f, "  {} {}: {}", label, self.location.cyan(), self.message)
+    }
+}
+
+pub fn error_count(issues: &[Issue]) -> usize {
+    issues.iter().filter(|issue| issue.is_error()).count()
+}
+
+/// The location of a JSON syntax or decoding error.
+pub fn json_error(error: &serde_json::Error) -> Issue {
+    let message = match error.classify() {
+        Category::Eof => tr!("validate.json_eof"),
+        _ => {
+            // The details come from serde_json, followed by the position
+            // which is given separately
+            let details = error.to_string();
+            let details = details
+                .rsplit_once(" at line ")
+                .map_or(&*details, |(d, _)| d);
+            tr!("validate.invalid_json", details)
+        }
+    };
+    Issue::error(
+        tr!("validate.line_column", error.line(), error.column()),
+        message,
+    )
+}
+
+/// Checks a holiday dataset, as published upstream, without assuming it is
+/// well-formed.
+pub fn check(json: &str) -> Vec<Issue> {
+    let value: Value = match serde_json::from_str(json) {
+        Ok(value) => value,
+        Err(e) => return vec![json_error(&e)],
+    };
+    let Some(years) = value.as_object() else {
+        return vec![Issue::error(
+            "years".to_string(),
+            tr!("validate.not_object"),
+        )];
+    };
+
+    let mut issues = Vec::new();
+    for (key, holidays) in years {
+        let location = format!("years[\"{}\"]", key);
+        let year = match key.trim().parse::<i32>() {
+            Ok(year) if PLAUSIBLE_YEARS.contains(&year) => Some(year),
+            Ok(year) => {
+                issues.push(Issue::error(
+                    location.clone(),
+                    tr!(
+                        "validate.year_out_of_range",
+                        year,
+                        PLAUSIBLE_YEARS.start(),
+                        PLAUSIBLE_YEARS.end()
+                    ),
+                ));
+                None
+            }
+            Err(_) => {
+                issues.push(Issue::error(
+                    location.clone(),
+                    tr!("validate.invalid_year_key", key),
+                ));
+                None
+            }
+        };
+
+        let Some(holidays) = holidays.as_array() else {
+            issues.push(Issue::error(location, tr!("validate.not_list")));
+            continue;
+        };
+        check_year(&location, year, holidays, &mut issues);
+    }
+    issues
+}
+
+fn check_year(location: &str, year: Option<i32>, holidays: &[Value], issues: &mut Vec<Issue>) {
+    let mut seen_dates: HashMap<NaiveDate, String> = HashMap::new();
+    let mut seen_names: HashMap<String, String> = HashMap::new();
+
+    for (index, holiday) in holidays.iter().enumerate() {
+        let location = format!("{}[{}]", location, index);
+        let Some(holiday) = holiday.as_object() else {
+            issues.push(Issue::error(location, tr!("validate.not_holiday")));
+            continue;
+        };
+
+        match holiday.get("name") {
+            Some(Value::String(name)) if name.trim().is_empty() => {
+                issues.push(Issue::error(location.clone(), tr!("validate.empty_name")));
+            }
+            Some(Value::String(name)) => {
+                let folded = name.trim().to_lowercase();
+                if let Some(first) = seen_names.get(&folded) {
+                    issues.push(Issue::warning(
+                        location.clone(),
+                        tr!("validate.duplicate_name", name, first),
+                    ));
+                } else {
+                    seen_names.insert(folded, location.clone());
+                }
+            }
+            Some(_) => issues.push(Issue::error(
+                location.clone(),
+                tr!("validate.not_text", "name"),
+            )),
+            None => issues.push(Issue::error(
+                location.clone(),
+                tr!("validate.missing_field", "name"),
+            )),
+        }
+
+        match date_field(holiday, "date", &location, issues) {
+            Some(date) => {
+                if let Some(year) = year.filter(|&year| year != date.year()) {
+                    issues.push(Issue::error(
+                        location.clone(),
+                        tr!("validate.wrong_year", date, year),
+                    ));
+                }
+                if let Some(first) = seen_dates.get(&date) {
+                    issues.push(Issue::warning(
+                        location.clone(),
+                        tr!("validate.duplicate_date", date, first),
+                    ));
+                } else {
+                    seen_dates.insert(date, location.clone());
+                }
+            }
+            None if !holiday.contains_key("date") => issues.push(Issue::error(
+                location.clone(),
+                tr!("validate.missing_field", "date"),
+            )),
+            None => {}
+        }
+
+        if holiday.get("observed").is_some_and(|v| !v.is_null()) {
+            date_field(holiday, "observed", &location, issues);
+        }
+
+        if let Some(kind) = holiday.get("kind").filter(|v| !v.is_null()) {
+            if serde_json::from_value::<HolidayKind>(kind.clone()).is_err() {
+                issues.push(Issue::error(
+                    location.clone(),
+                    tr!("validate.unknown_kind", kind),
+                ));
+            }
+        }
+    }
+}
+
+/// Compares the years a country manifest lists with the ones the dataset
+/// has.
+pub fn check_years(listed: &[i32], available: &[i32]) -> Vec<Issue> {
+    listed
+        .iter()
+        .filter(|year| !available.contains(year))
+        .map(|year| Issue::warning(format!("years[\"{}\"]", year), tr!("validate.missing_year")))
+        .collect()
+}
+
+/// Parses a `YYYY-MM-DD` field, reporting it when present but invalid.
+fn date_field(
+    holiday: &Map<String, Value>,
+    field: &str,
+    location: &str,
+    issues: &mut Vec<Issue>,
+) -> Option<NaiveDate> {
+    let value = holiday.get(field)?;
+    let date = value
+        .as_str()
+        .and_then(|text| NaiveDate::parse_from_str(text, "%Y-%m-%d").ok());
+    if date.is_none() {
+        issues.push(Issue::error(
+            location.to_string(),
+            tr!("validate.invalid_date", field, value),
+        ));
+    }
+    date
+}
+
+/// Prints the findings for one dataset, `source` being a country code or a
+/// file name.
+pub fn print_report(source: &str, issues: &[Issue]) {
+    if issues.is_empty() {
+        println!("{}", tr!("validate.ok", source).bold().green());
+        return;
+    }
+
+    let errors = error_count(issues);
+    let summary = tr!("validate.summary", source, errors, issues.len() - errors);
+    if errors > 0 {
+        println!("{}", summary.bold().red());
+    } else {
+        println!("{}", summary.bold().yellow());
+    }
+    for issue in issues {
+        println!("{}", issue);
+    }
+}